@@ -1,14 +1,30 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::cli;
+use advent_of_code::template::commands::{
+    all, download, fuzz_day, leaderboard, read, readme, report, scaffold, scale, snapshots, solve,
+    stats, time,
+};
+use advent_of_code::template::config::{self, Config};
+use advent_of_code::template::Status;
 use args::{parse, AppArguments};
+use std::process::ExitCode;
+use std::time::Duration;
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
 mod args {
+    use advent_of_code::template::cli::{self, Command, UsageError};
+    use advent_of_code::template::fuzz::FuzzOptions;
+    use advent_of_code::template::readme_benchmarks::{
+        parse_columns, Column, SortOrder, TableOptions, Unit,
+    };
+    use advent_of_code::template::runner::Submit;
+    use advent_of_code::template::scale::ScaleOptions;
+    use advent_of_code::template::timings::ExportFormat;
+    use advent_of_code::template::viz::{Format, VizOptions};
     use advent_of_code::template::Day;
-    use std::process;
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use std::{env, ffi::OsString, path::PathBuf};
 
     pub enum AppArguments {
         Download {
@@ -26,119 +42,495 @@ mod args {
             day: Day,
             release: bool,
             dhat: bool,
-            submit: Option<u8>,
+            spans: bool,
+            fetch: Option<bool>,
+            submit: Option<Submit>,
+            viz: Option<VizOptions>,
+            timeout_secs: Option<u64>,
         },
         All {
             release: bool,
+            timeout_secs: Option<u64>,
+        },
+        Scale {
+            day: Day,
+            options: ScaleOptions,
+            timeout_secs: Option<u64>,
+        },
+        FuzzDay {
+            day: Day,
+            options: FuzzOptions,
+            timeout_secs: Option<u64>,
+        },
+        Snapshots {
+            day: Option<Day>,
+            update: bool,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            matrix: bool,
+            profiles: bool,
+            timeout_secs: Option<u64>,
+            table: TableArguments,
+            export: Option<ExportFormat>,
+            output: Option<PathBuf>,
+        },
+        Stats,
+        Readme {
+            table: TableArguments,
+        },
+        Report {
+            output: Option<PathBuf>,
+        },
+        Leaderboard {
+            file: Option<PathBuf>,
+            day: Option<Day>,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
+        Completions {
+            shell: String,
+        },
+        Help {
+            command: Option<&'static Command>,
+        },
+    }
+
+    /// Flags that override the benchmark table options from `aoc.toml`.
+    pub struct TableArguments {
+        columns: Option<Vec<Column>>,
+        sort: Option<SortOrder>,
+        unit: Option<Unit>,
+        highlight: bool,
+    }
+
+    impl TableArguments {
+        fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+            Ok(Self {
+                columns: args.opt_value_from_fn("--columns", parse_columns)?,
+                sort: args.opt_value_from_str("--sort")?,
+                unit: args.opt_value_from_str("--unit")?,
+                highlight: args.contains("--highlight"),
+            })
+        }
+
+        pub fn apply(self, options: &TableOptions) -> TableOptions {
+            options
+                .clone()
+                .with_overrides(self.columns, self.sort, self.unit, self.highlight)
+        }
+    }
+
+    /// Flags accepted by every command that override values from `aoc.toml`.
+    pub struct GlobalArguments {
+        config: Option<String>,
+        year: Option<u16>,
+        data_dir: Option<String>,
+    }
+
+    impl GlobalArguments {
+        /// Exports the overrides as environment variables, so child processes pick them up as well.
+        pub fn export(&self) {
+            if let Some(config) = &self.config {
+                env::set_var("AOC_CONFIG", config);
+            }
+            if let Some(year) = self.year {
+                env::set_var("AOC_YEAR", year.to_string());
+            }
+            if let Some(data_dir) = &self.data_dir {
+                env::set_var("AOC_DATA_DIR", data_dir);
+            }
+        }
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+    pub fn parse() -> Result<(AppArguments, GlobalArguments), UsageError> {
+        let raw: Vec<String> = env::args().skip(1).collect();
+        let mut args = pico_args::Arguments::from_vec(raw.iter().map(OsString::from).collect());
+
+        let global_err = |e: pico_args::Error| UsageError::new(e.to_string(), None);
+        let global_args = GlobalArguments {
+            config: args.opt_value_from_str("--config").map_err(global_err)?,
+            year: args.opt_value_from_str("--year").map_err(global_err)?,
+            data_dir: args.opt_value_from_str("--data-dir").map_err(global_err)?,
+        };
+
+        let Some(name) = args.subcommand().map_err(global_err)? else {
+            if args.contains(["-h", "--help"]) {
+                return Ok((AppArguments::Help { command: None }, global_args));
+            }
+            return Err(UsageError::new("no command specified.", None));
+        };
 
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
+        let command = cli::parse_command(&name)?;
+        cli::check_flags(command, &raw)?;
+
+        if args.contains(["-h", "--help"]) {
+            return Ok((
+                AppArguments::Help {
+                    command: Some(command),
+                },
+                global_args,
+            ));
+        }
+
+        let err = |e: pico_args::Error| UsageError::new(e.to_string(), Some(command));
+
+        let app_args = match command.name {
+            "all" => AppArguments::All {
                 release: args.contains("--release"),
+                timeout_secs: args.opt_value_from_str("--timeout").map_err(err)?,
             },
-            Some("time") => {
+            "time" => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let matrix = args.contains("--matrix");
+                let profiles = args.contains("--profiles");
+                let export: Option<ExportFormat> =
+                    args.opt_value_from_str("--export").map_err(err)?;
+                let output: Option<PathBuf> = args.opt_value_from_str("--output").map_err(err)?;
+
+                if output.is_some() && export.is_none() {
+                    return Err(UsageError::new(
+                        "`--output` requires `--export`.",
+                        Some(command),
+                    ));
+                }
 
-                AppArguments::Time {
+                let time = AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    timeout_secs: args.opt_value_from_str("--timeout").map_err(err)?,
+                    table: TableArguments::parse(&mut args).map_err(err)?,
+                    day: args.opt_free_from_str().map_err(err)?,
                     store,
+                    matrix,
+                    profiles,
+                    export,
+                    output,
+                };
+
+                if let AppArguments::Time {
+                    day, export: Some(_), ..
+                } = &time
+                {
+                    if all || store || matrix || profiles || day.is_some() {
+                        return Err(UsageError::new(
+                            "`--export` only exports stored timings and can't be combined with a day, `--all`, `--store`, `--matrix` or `--profiles`.",
+                            Some(command),
+                        ));
+                    }
+                }
+
+                if matrix && profiles {
+                    return Err(UsageError::new(
+                        "`--matrix` and `--profiles` can't be combined, run them one after the other.",
+                        Some(command),
+                    ));
                 }
+
+                if matrix && store {
+                    return Err(UsageError::new(
+                        "`--matrix` compares builds with different features and can't be combined with `--store`.",
+                        Some(command),
+                    ));
+                }
+                time
             }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+            "download" => AppArguments::Download {
+                day: args.free_from_str().map_err(err)?,
             },
-            Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+            "read" => AppArguments::Read {
+                day: args.free_from_str().map_err(err)?,
             },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+            "scaffold" => AppArguments::Scaffold {
+                day: args.free_from_str().map_err(err)?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+            "solve" => {
+                let viz: Option<PathBuf> = args.opt_value_from_str("--viz").map_err(err)?;
+                let format: Option<Format> =
+                    args.opt_value_from_str("--viz-format").map_err(err)?;
+
+                if format.is_some() && viz.is_none() {
+                    return Err(UsageError::new(
+                        "`--viz-format` needs a `--viz` folder to write to.",
+                        Some(command),
+                    ));
+                }
+
+                let release = args.contains("--release");
+                let submit = args.opt_value_from_str("--submit").map_err(err)?;
+                let dhat = args.contains("--dhat");
+                let spans = args.contains("--spans");
+                let fetch = match (args.contains("--fetch"), args.contains("--no-fetch")) {
+                    (true, true) => {
+                        return Err(UsageError::new(
+                            "`--fetch` and `--no-fetch` can't be combined.",
+                            Some(command),
+                        ))
+                    }
+                    (true, false) => Some(true),
+                    (false, true) => Some(false),
+                    (false, false) => None,
+                };
+                let timeout_secs = args.opt_value_from_str("--timeout").map_err(err)?;
+                let day: Day = args.free_from_str().map_err(err)?;
+
+                AppArguments::Solve {
+                    release,
+                    submit,
+                    dhat,
+                    spans,
+                    fetch,
+                    // exports are named after the day and part, e.g. `out/14-2.gif`.
+                    viz: viz.map(|folder| VizOptions {
+                        prefix: folder.join(day.to_string()),
+                        format: format.unwrap_or_default(),
+                    }),
+                    timeout_secs,
+                    day,
+                }
+            }
+            "scale" => {
+                let part: Option<u8> = args.opt_value_from_str("--part").map_err(err)?;
+                let steps: Option<u32> = args.opt_value_from_str("--steps").map_err(err)?;
+                let max: Option<usize> = args.opt_value_from_str("--max").map_err(err)?;
+
+                if part.is_some_and(|part| part != 1 && part != 2) || steps == Some(0) {
+                    return Err(UsageError::new(
+                        "`--part` must be 1 or 2 and `--steps` at least 1.",
+                        Some(command),
+                    ));
+                }
+
+                AppArguments::Scale {
+                    options: ScaleOptions {
+                        steps: steps.unwrap_or(ScaleOptions::default().steps),
+                        max,
+                        part,
+                    },
+                    timeout_secs: args.opt_value_from_str("--timeout").map_err(err)?,
+                    day: args.free_from_str().map_err(err)?,
+                }
+            }
+            "fuzz-day" => {
+                let count: Option<u64> = args.opt_value_from_str("--count").map_err(err)?;
+                let size: Option<usize> = args.opt_value_from_str("--size").map_err(err)?;
+
+                if count == Some(0) || size == Some(0) {
+                    return Err(UsageError::new(
+                        "`--count` and `--size` must be at least 1.",
+                        Some(command),
+                    ));
+                }
+
+                let defaults = FuzzOptions::default();
+                AppArguments::FuzzDay {
+                    options: FuzzOptions {
+                        // a new seed per run, printed with any failure so it can be reproduced.
+                        seed: match args.opt_value_from_str("--seed").map_err(err)? {
+                            Some(seed) => seed,
+                            None => SystemTime::now()
+                                .duration_since(UNIX_EPOCH)
+                                .map_or(defaults.seed, |d| d.as_secs()),
+                        },
+                        count: count.unwrap_or(defaults.count),
+                        size: size.unwrap_or(defaults.size),
+                        save: args.contains("--save"),
+                    },
+                    timeout_secs: args.opt_value_from_str("--timeout").map_err(err)?,
+                    day: args.free_from_str().map_err(err)?,
+                }
+            }
+            "snapshots" => AppArguments::Snapshots {
+                update: args.contains("--update-snapshots"),
+                day: args.opt_free_from_str().map_err(err)?,
+            },
+            "stats" => AppArguments::Stats,
+            "readme" => AppArguments::Readme {
+                table: TableArguments::parse(&mut args).map_err(err)?,
+            },
+            "report" => AppArguments::Report {
+                output: args.opt_value_from_str("--output").map_err(err)?,
+            },
+            "leaderboard" => AppArguments::Leaderboard {
+                file: args.opt_value_from_str("--file").map_err(err)?,
+                day: args.opt_value_from_str("--day").map_err(err)?,
             },
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
-            Some(x) => {
-                eprintln!("Unknown command: {x}");
-                process::exit(1);
+            "today" => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
+            #[cfg(not(feature = "today"))]
+            "today" => {
+                return Err(UsageError::new(
+                    "the `today` command requires the `today` feature. Run it via the `cargo today` alias.",
+                    Some(command),
+                ))
+            }
+            "completions" => {
+                let shell: String = args.free_from_str().map_err(err)?;
+                if !cli::SHELLS.contains(&shell.as_str()) {
+                    return Err(UsageError::new(
+                        format!(
+                            "unsupported shell `{shell}`, expecting one of {}.",
+                            cli::SHELLS.join(", ")
+                        ),
+                        Some(command),
+                    ));
+                }
+                AppArguments::Completions { shell }
             }
-            None => {
-                eprintln!("No command specified.");
-                process::exit(1);
+            "help" => {
+                let name: Option<String> = args.opt_free_from_str().map_err(err)?;
+                AppArguments::Help {
+                    command: name.map(|name| cli::parse_command(&name)).transpose()?,
+                }
             }
+            _ => unreachable!("command `{}` is missing a parser.", command.name),
         };
 
         let remaining = args.finish();
         if !remaining.is_empty() {
-            eprintln!("Warning: unknown argument(s): {remaining:?}.");
+            let remaining: Vec<_> = remaining.iter().map(|arg| arg.to_string_lossy()).collect();
+            return Err(UsageError::new(
+                format!("unexpected argument(s): {}.", remaining.join(" ")),
+                Some(command),
+            ));
         }
 
-        Ok(app_args)
+        Ok((app_args, global_args))
     }
 }
 
-fn main() {
-    match parse() {
+fn main() -> ExitCode {
+    let status = match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
-            std::process::exit(1);
+            Status::Usage
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
-                day,
-                download,
-                overwrite,
-            } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
+        Ok((args, global_args)) => {
+            global_args.export();
+            match config::init() {
+                Ok(config) => dispatch(args, config),
+                Err(err) => {
+                    eprintln!("Error: {err}");
+                    Status::Failure
                 }
             }
-            AppArguments::Solve {
-                day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
-        },
+        }
     };
+
+    status.into()
+}
+
+fn dispatch(args: AppArguments, config: &Config) -> Status {
+    let timeout = |secs: Option<u64>| secs.or(config.timeout_secs).map(Duration::from_secs);
+
+    match args {
+        AppArguments::Help { command } => {
+            match command {
+                Some(command) => print!("{}", cli::help(command)),
+                None => print!("{}", cli::overview()),
+            };
+            Status::Success
+        }
+        AppArguments::Completions { shell } => {
+            // NOTE: the shell has been validated while parsing arguments.
+            print!("{}", cli::completions(&shell).unwrap_or_default());
+            Status::Success
+        }
+        AppArguments::All {
+            release,
+            timeout_secs,
+        } => all::handle(release || config.release, timeout(timeout_secs)),
+        AppArguments::Time {
+            export: Some(format),
+            output,
+            ..
+        } => time::export(format, output),
+        AppArguments::Time {
+            day,
+            matrix: true,
+            timeout_secs,
+            ..
+        } => time::matrix(day, timeout(timeout_secs)),
+        AppArguments::Time {
+            day,
+            profiles: true,
+            store,
+            timeout_secs,
+            ..
+        } => time::profiles(day, store, timeout(timeout_secs)),
+        AppArguments::Time {
+            day,
+            all,
+            store,
+            timeout_secs,
+            table,
+            ..
+        } => time::handle(
+            day,
+            all,
+            store,
+            timeout(timeout_secs),
+            &table.apply(&config.readme.benchmarks),
+        ),
+        AppArguments::Download { day } => download::handle(day),
+        AppArguments::Read { day } => read::handle(day),
+        AppArguments::Scaffold {
+            day,
+            download,
+            overwrite,
+        } => {
+            let status = scaffold::handle(day, overwrite);
+            if download && status.is_success() {
+                download::handle(day)
+            } else {
+                status
+            }
+        }
+        AppArguments::Solve {
+            day,
+            release,
+            dhat,
+            spans,
+            fetch,
+            submit,
+            viz,
+            timeout_secs,
+        } => solve::handle(
+            day,
+            release || config.release,
+            dhat,
+            spans,
+            fetch.unwrap_or(config.auto_fetch),
+            submit,
+            viz,
+            timeout(timeout_secs),
+        ),
+        AppArguments::Scale {
+            day,
+            options,
+            timeout_secs,
+        } => scale::handle(day, options, timeout(timeout_secs)),
+        AppArguments::FuzzDay {
+            day,
+            options,
+            timeout_secs,
+        } => fuzz_day::handle(day, options, timeout(timeout_secs)),
+        AppArguments::Snapshots { day, update } => snapshots::handle(day, update),
+        AppArguments::Stats => stats::handle(),
+        AppArguments::Readme { table } => readme::handle(&table.apply(&config.readme.benchmarks)),
+        AppArguments::Report { output } => {
+            report::handle(output.unwrap_or_else(|| config.report_path()))
+        }
+        AppArguments::Leaderboard { file, day } => leaderboard::handle(file, day),
+        #[cfg(feature = "today")]
+        AppArguments::Today { wait } => today::handle(wait),
+    }
 }
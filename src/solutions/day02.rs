@@ -0,0 +1,65 @@
+use crate::template::input::Options;
+use crate::template::Day;
+
+/// The current day.
+pub const DAY: Day = crate::day!(2);
+
+/// How the real input is normalized and checked.
+pub const INPUT_OPTIONS: Options = Options::DEFAULT;
+
+/// The levels of every report.
+pub fn parse(input: &str) -> Vec<Vec<u32>> {
+    input
+        .lines()
+        .map(|line| line.split_whitespace().filter_map(|s| s.parse().ok()).collect())
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Some(parse(input).iter().filter(|levels| is_safe(levels)).count() as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Some(parse(input).iter().filter(|levels| {
+        if is_safe(levels) {
+            return true;
+        }
+        
+        (0..levels.len()).any(|i| {
+            let mut temp_levels = levels.to_vec();
+            temp_levels.remove(i);
+            is_safe(&temp_levels)
+        })
+    }).count() as u32)
+}
+
+fn is_safe(levels: &[u32]) -> bool {
+    let is_increasing = levels.windows(2).all(|pair| pair[1] > pair[0]);
+    let is_decreasing = levels.windows(2).all(|pair| pair[1] < pair[0]);
+
+    if !(is_increasing || is_decreasing) {
+        return false;
+    }
+
+    levels.windows(2).all(|pair| {
+        let diff = (pair[1] as i32 - pair[0] as i32).abs();
+        (1..=3).contains(&diff)
+    })  
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(4));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::template::input::Options;
+use crate::template::Day;
+use regex::Regex;
+
+/// The current day.
+pub const DAY: Day = crate::day!(3);
+
+/// How the real input is normalized and checked.
+pub const INPUT_OPTIONS: Options = Options::DEFAULT;
+
+/// The operands of every valid `mul` instruction in the memory.
+pub fn parse(memory: &str) -> Vec<(u32, u32)> {
+    let re = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
+
+    re.captures_iter(memory)
+        .filter_map(|cap| {
+            let num1 = cap[1].parse::<u32>().ok()?;
+            let num2 = cap[2].parse::<u32>().ok()?;
+            Some((num1, num2))
+        })
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let add_result: u32 = parse(input).iter().map(|(num1, num2)| num1 * num2).sum();
+
+    Some(add_result)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let flattened_input = input.replace('\n', "");
+
+    let ignore_re = Regex::new(r"(?s)don't\(\).*?do\(\)|(?s)don't\(\).*").unwrap();
+    let cleaned_input = ignore_re.replace_all(&flattened_input, "");
+
+    let add_result: u32 = parse(&cleaned_input)
+        .iter()
+        .map(|(num1, num2)| num1 * num2)
+        .sum();
+
+    Some(add_result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(161));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+
+        assert_eq!(result, Some(48));
+    }
+}
@@ -0,0 +1,168 @@
+use crate::template::fuzz::{Generator, Rng};
+use crate::template::hash::HashMap;
+use crate::template::input::Options;
+use crate::template::{Day, Shape};
+
+/// The current day.
+pub const DAY: Day = crate::day!(11);
+
+/// How the real input is normalized and checked.
+pub const INPUT_OPTIONS: Options = Options {
+    shape: Shape::SingleLine,
+    generator: Some(Stones::generate),
+    ..Options::DEFAULT
+};
+
+/// `size` stones with digit counts from 1 to 6, for `cargo scale` and `cargo fuzz-day`.
+pub struct Stones;
+
+impl Generator for Stones {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let stones: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let digits = rng.between(1, 6) as u32;
+                rng.below(10usize.pow(digits)).to_string()
+            })
+            .collect();
+        stones.join(" ") + "\n"
+    }
+}
+
+/// The numbers engraved on the stones, in order.
+pub fn parse(input: &str) -> Vec<&str> {
+    input.split_whitespace().collect()
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let stones = parse(input);
+    let mut current_stones: Vec<String> = stones.iter().map(|&s| s.to_string()).collect();
+
+    for _ in 0..25 {
+        let mut new_stones: Vec<String> = Vec::new();
+        for stone in &current_stones {
+            if *stone == "0" {
+                new_stones.push("1".to_string());
+            } else if stone.len() % 2 == 0 {
+                let left: u32 = stone[0..stone.len() / 2].parse().unwrap();
+                let right: u32 = stone[stone.len() / 2..].parse().unwrap();
+                new_stones.push(left.to_string());
+                new_stones.push(right.to_string());
+            } else {
+
+                let value: usize = stone.parse::<usize>().unwrap();
+                new_stones.push((value * 2024).to_string());
+            }
+        }
+        current_stones = new_stones;
+    }
+
+    Some(current_stones.len())
+}
+
+fn count_stones(stone: &str, steps: usize, memo: &mut HashMap<(String, usize), usize>) -> usize {
+    if steps == 0 {
+        return 1;
+    }
+
+    // check if memo has it
+    if let Some(&res) = memo.get(&(stone.to_string(), steps)) {
+        return res;
+    }
+
+    // do one blink
+    let result = if stone == "0" {
+        count_stones("1", steps - 1, memo)
+
+    } else if stone.len().is_multiple_of(2) {
+        let half = stone.len() / 2;
+        let left_val = stone[..half].parse::<u64>().unwrap();
+        let right_val = stone[half..].parse::<u64>().unwrap();
+
+        count_stones(&left_val.to_string(), steps - 1, memo)
+            + count_stones(&right_val.to_string(), steps - 1, memo)
+
+    } else {
+        let value = stone.parse::<u64>().unwrap();
+        let multiplied = value * 2024; 
+
+        let new_stone = multiplied.to_string();
+        count_stones(&new_stone, steps - 1, memo)
+    };
+
+    memo.insert((stone.to_string(), steps), result);
+    result
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let stones = parse(input);
+    let mut memo = HashMap::default();
+    let mut total = 0;
+
+    for s in stones {
+        total += count_stones(s, 75, &mut memo);
+    }
+
+    Some(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::differential::Differential;
+
+    /// Blinks every stone one by one, as a reference for the memoized `count_stones`.
+    fn blink_naive(input: &str, blinks: usize) -> usize {
+        let mut stones: Vec<u64> = input
+            .split_whitespace()
+            .map(|s| s.parse().unwrap())
+            .collect();
+
+        for _ in 0..blinks {
+            stones = stones
+                .iter()
+                .flat_map(|&stone| {
+                    let digits = if stone == 0 { 1 } else { stone.ilog10() + 1 };
+                    if stone == 0 {
+                        vec![1]
+                    } else if digits % 2 == 0 {
+                        let half = 10u64.pow(digits / 2);
+                        vec![stone / half, stone % half]
+                    } else {
+                        vec![stone * 2024]
+                    }
+                })
+                .collect();
+        }
+
+        stones.len()
+    }
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(55312));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(65601038650482));
+    }
+
+    #[test]
+    fn test_count_stones_against_reference() {
+        let memoized = |input: &str| {
+            let mut memo = HashMap::default();
+            input
+                .split_whitespace()
+                .map(|stone| count_stones(stone, 25, &mut memo))
+                .sum::<usize>()
+        };
+
+        Differential::new(DAY, &INPUT_OPTIONS)
+            .example()
+            .real_input()
+            .generated(0..20, 6)
+            .assert_agree(|input| blink_naive(input, 25), memoized);
+    }
+}
@@ -0,0 +1,202 @@
+use crate::template::fuzz::{Generator, Rng};
+use crate::template::input::Options;
+use crate::template::{Day, Shape};
+
+/// The current day.
+pub const DAY: Day = crate::day!(19);
+
+/// How the real input is normalized and checked.
+pub const INPUT_OPTIONS: Options = Options {
+    shape: Shape::Sections(2),
+    generator: Some(Towels::generate),
+    ..Options::DEFAULT
+};
+
+/// Up to eight short towel patterns, followed by `size` designs that are often made of them.
+pub struct Towels;
+
+impl Generator for Towels {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let random_stripes = |rng: &mut Rng, length: usize| -> String {
+            (0..length)
+                .map(|_| *rng.pick(&['w', 'u', 'b', 'r', 'g']))
+                .collect()
+        };
+
+        let mut patterns: Vec<String> = vec![];
+        for _ in 0..rng.between(3, 8) {
+            let length = rng.between(1, 3);
+            let pattern = random_stripes(rng, length);
+            if !patterns.contains(&pattern) {
+                patterns.push(pattern);
+            }
+        }
+
+        let mut input = patterns.join(", ") + "\n\n";
+        for _ in 0..size.max(1) {
+            let mut design = String::new();
+            while design.len() < 12 && (design.is_empty() || rng.chance(3, 4)) {
+                if rng.chance(9, 10) {
+                    let pattern = rng.pick(&patterns);
+                    design.push_str(pattern);
+                } else {
+                    design.push_str(&random_stripes(rng, 1));
+                }
+            }
+            input.push_str(&design);
+            input.push('\n');
+        }
+        input
+    }
+}
+
+fn design_possible(
+    patterns: &Vec<&str>, 
+    design: &str, 
+    index: usize, 
+    memo: &mut Vec<Option<bool>>
+) -> bool {
+    // check if we already computed result for this index
+    if let Some(cached) = memo[index] {
+        return cached;
+    }
+
+    // we reached the end of the design
+    if index == design.len() {
+        memo[index] = Some(true);
+        return true;
+    }
+
+    // try each pattern at current index
+    for &p in patterns {
+        if index + p.len() <= design.len() && p == &design[index..index + p.len()]
+            && design_possible(patterns, design, index + p.len(), memo) {
+                memo[index] = Some(true);
+                return true;
+            }
+    }
+
+    // no pattern works
+    memo[index] = Some(false);
+    false
+}
+
+/// The available towel patterns and the desired designs.
+pub fn parse(input: &str) -> Option<(Vec<&str>, Vec<&str>)> {
+    let (patterns_input, designs_input) = input.split_once("\n\n")?;
+    let patterns: Vec<&str> = patterns_input
+        .split(", ")
+        .filter(|p| !p.is_empty())
+        .collect();
+
+    let designs: Vec<&str> = designs_input.lines().collect();
+
+    Some((patterns, designs))
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (patterns, designs) = parse(input)?;
+
+    let mut possible_design_count: u32 = 0;
+
+    for design in designs {
+        // memo table
+        let mut memo = vec![None; design.len() + 1];
+        if design_possible(&patterns, design, 0, &mut memo) {
+            possible_design_count += 1;
+        }
+    }
+
+    Some(possible_design_count)
+}
+
+fn count_designs(
+    patterns: &Vec<&str>, 
+    design: &str, 
+    index: usize, 
+    memo: &mut Vec<Option<u64>>
+) -> u64 {
+    // check if we already computed result for this index
+    if let Some(cached) = memo[index] {
+        return cached;
+    }
+
+    // we reached the end of the design
+    if index == design.len() {
+        memo[index] = Some(1);
+        return 1;
+    }
+
+    let mut total_ways = 0;
+
+    // try each pattern at current index
+    for &p in patterns {
+        if index + p.len() <= design.len() && p == &design[index..index + p.len()] {
+            total_ways += count_designs(patterns, design, index + p.len(), memo);
+            }
+        }
+
+    memo[index] = Some(total_ways);
+    total_ways
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let (patterns, designs) = parse(input)?;
+
+    let mut total_count = 0;
+
+    for design in designs {
+        let mut memo = vec![None; design.len() + 1];
+        total_count += count_designs(&patterns, design, 0, &mut memo);
+    }
+
+    Some(total_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::differential::Differential;
+
+    /// Tries every pattern at every position without memoization, as a reference for `count_designs`.
+    fn count_brute_force(patterns: &[&str], design: &str) -> u64 {
+        if design.is_empty() {
+            return 1;
+        }
+        patterns
+            .iter()
+            .filter(|&&p| design.starts_with(p))
+            .map(|p| count_brute_force(patterns, &design[p.len()..]))
+            .sum()
+    }
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(6));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(16));
+    }
+
+    #[test]
+    fn test_count_designs_against_reference() {
+        let brute_force = |input: &str| {
+            let (patterns, designs) = input.split_once("\n\n").unwrap();
+            let patterns: Vec<&str> = patterns.split(", ").collect();
+            designs
+                .lines()
+                .map(|design| count_brute_force(&patterns, design))
+                .sum::<u64>()
+        };
+
+        // the real input has too many arrangements to count one by one.
+        Differential::new(DAY, &INPUT_OPTIONS)
+            .example()
+            .generated(0..50, 10)
+            .assert_agree(|input| Some(brute_force(input)), part_two);
+    }
+}
@@ -0,0 +1,257 @@
+/// A bump allocator for the `bump-alloc` feature, which `solution!` installs as the global allocator.
+/// Small allocations are carved out of 1 MiB chunks by moving a pointer forward, and freeing one only
+/// counts down the live allocations of its chunk. A chunk is reused once all of its allocations are
+/// freed, so memory grows with the peak number of live chunks rather than with every allocation.
+/// Large allocations go straight to the system allocator.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(all(feature = "bump-alloc", feature = "dhat-heap"))]
+compile_error!(
+    "the `bump-alloc` and `dhat-heap` features both set the global allocator, enable only one."
+);
+
+const CHUNK_SIZE: usize = 1 << 20;
+/// Allocations above this size, or aligned to more than a page, skip the chunks.
+const MAX_SMALL_SIZE: usize = CHUNK_SIZE / 4;
+const MAX_SMALL_ALIGN: usize = 4096;
+
+/// Start of every chunk. Data starts after it, at [`DATA_START`].
+struct Header {
+    /// Allocations in this chunk that have not been freed.
+    live: usize,
+    /// Next chunk in the list of empty chunks.
+    next_free: *mut Header,
+}
+
+const DATA_START: usize = 64;
+const _: () = assert!(std::mem::size_of::<Header>() <= DATA_START);
+
+struct State {
+    /// Chunk allocations are currently carved from, null before the first allocation.
+    current: *mut Header,
+    /// Offset of the next free byte in `current`.
+    offset: usize,
+    /// Empty chunks that are not `current`.
+    free: *mut Header,
+}
+
+pub struct Bump {
+    locked: AtomicBool,
+    state: std::cell::UnsafeCell<State>,
+}
+
+// SAFETY: `state` is only accessed while holding `locked`.
+unsafe impl Sync for Bump {}
+
+impl Default for Bump {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Bump {
+    pub const fn new() -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+            state: std::cell::UnsafeCell::new(State {
+                current: ptr::null_mut(),
+                offset: 0,
+                free: ptr::null_mut(),
+            }),
+        }
+    }
+
+    fn is_small(layout: Layout) -> bool {
+        layout.size() <= MAX_SMALL_SIZE && layout.align() <= MAX_SMALL_ALIGN
+    }
+
+    fn chunk_layout() -> Layout {
+        // a chunk is aligned to its size, so masking a pointer finds its header.
+        Layout::from_size_align(CHUNK_SIZE, CHUNK_SIZE).expect("valid chunk layout")
+    }
+
+    fn chunk_of(ptr: *mut u8) -> *mut Header {
+        ptr.map_addr(|addr| addr & !(CHUNK_SIZE - 1)).cast()
+    }
+
+    /// Runs `f` on the state while holding the spin lock.
+    fn with_state<T>(&self, f: impl FnOnce(&mut State) -> T) -> T {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            std::hint::spin_loop();
+        }
+        // SAFETY: the lock is held, so this is the only reference to the state.
+        let result = f(unsafe { &mut *self.state.get() });
+        self.locked.store(false, Ordering::Release);
+        result
+    }
+
+    /// Makes an empty chunk current, moving the previous one to the free list if it is empty.
+    ///
+    /// # Safety
+    /// All chunks in `state` must be valid.
+    unsafe fn next_chunk(state: &mut State) -> bool {
+        let chunk = if state.free.is_null() {
+            let chunk: *mut Header = System.alloc(Self::chunk_layout()).cast();
+            if chunk.is_null() {
+                return false;
+            }
+            chunk
+        } else {
+            let chunk = state.free;
+            state.free = (*chunk).next_free;
+            chunk
+        };
+
+        if !state.current.is_null() && (*state.current).live == 0 {
+            (*state.current).next_free = state.free;
+            state.free = state.current;
+        }
+
+        chunk.write(Header {
+            live: 0,
+            next_free: ptr::null_mut(),
+        });
+        state.current = chunk;
+        state.offset = DATA_START;
+        true
+    }
+}
+
+unsafe impl GlobalAlloc for Bump {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if !Self::is_small(layout) {
+            return System.alloc(layout);
+        }
+
+        self.with_state(|state| {
+            let mut start = state.offset.next_multiple_of(layout.align());
+            if state.current.is_null() || start + layout.size() > CHUNK_SIZE {
+                if !Self::next_chunk(state) {
+                    return ptr::null_mut();
+                }
+                start = state.offset.next_multiple_of(layout.align());
+            }
+
+            (*state.current).live += 1;
+            state.offset = start + layout.size();
+            state.current.cast::<u8>().add(start)
+        })
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if !Self::is_small(layout) {
+            System.dealloc(ptr, layout);
+            return;
+        }
+
+        self.with_state(|state| {
+            let chunk = Self::chunk_of(ptr);
+            (*chunk).live -= 1;
+            if (*chunk).live > 0 {
+                return;
+            }
+
+            if chunk == state.current {
+                state.offset = DATA_START;
+            } else {
+                (*chunk).next_free = state.free;
+                state.free = chunk;
+            }
+        });
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+
+        if Self::is_small(layout) && Self::is_small(new_layout) {
+            // grow or shrink the latest allocation in place, e.g. a `Vec` that is being pushed to.
+            let resized = self.with_state(|state| {
+                if Self::chunk_of(ptr) != state.current
+                    || ptr.add(layout.size()) != state.current.cast::<u8>().add(state.offset)
+                {
+                    return false;
+                }
+                let start = state.offset - layout.size();
+                if start + new_size > CHUNK_SIZE {
+                    return false;
+                }
+                state.offset = start + new_size;
+                true
+            });
+            if resized {
+                return ptr;
+            }
+        } else if !Self::is_small(layout) && !Self::is_small(new_layout) {
+            return System.realloc(ptr, layout, new_size);
+        }
+
+        let new_ptr = self.alloc(new_layout);
+        if !new_ptr.is_null() {
+            ptr::copy_nonoverlapping(ptr, new_ptr, layout.size().min(new_size));
+            self.dealloc(ptr, layout);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::alloc::{GlobalAlloc, Layout};
+
+    use super::{Bump, CHUNK_SIZE, DATA_START};
+
+    #[test]
+    fn bumps_and_reuses_chunks() {
+        let bump = Bump::new();
+        let layout = Layout::from_size_align(24, 8).unwrap();
+
+        unsafe {
+            let a = bump.alloc(layout);
+            let b = bump.alloc(layout);
+            assert_eq!(b as usize - a as usize, 24);
+            assert_eq!(a as usize % CHUNK_SIZE, DATA_START);
+
+            bump.dealloc(a, layout);
+            bump.dealloc(b, layout);
+            // the chunk is empty, so allocation starts over.
+            assert_eq!(bump.alloc(layout), a);
+        }
+    }
+
+    #[test]
+    fn respects_alignment() {
+        let bump = Bump::new();
+        unsafe {
+            bump.alloc(Layout::from_size_align(3, 1).unwrap());
+            let aligned = bump.alloc(Layout::from_size_align(16, 64).unwrap());
+            assert_eq!(aligned as usize % 64, 0);
+            let large = bump.alloc(Layout::from_size_align(CHUNK_SIZE, 8).unwrap());
+            assert!(!large.is_null());
+            bump.dealloc(large, Layout::from_size_align(CHUNK_SIZE, 8).unwrap());
+        }
+    }
+
+    #[test]
+    fn grows_the_latest_allocation_in_place() {
+        let bump = Bump::new();
+        let layout = Layout::from_size_align(8, 8).unwrap();
+        unsafe {
+            let first = bump.alloc(layout);
+            first.write(7);
+            let grown = bump.realloc(first, layout, 64);
+            assert_eq!(grown, first);
+
+            let other = bump.alloc(layout);
+            let moved = bump.realloc(grown, Layout::from_size_align(64, 8).unwrap(), 128);
+            assert_ne!(moved, grown);
+            assert!(moved > other);
+            assert_eq!(moved.read(), 7);
+        }
+    }
+}
@@ -0,0 +1,372 @@
+/// The answer ledger records every answer submitted via aoc-cli together with its verdict.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::event::Event;
+use crate::template::{config, Day};
+
+/// Outcome of a submission, parsed from the response of the puzzle server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    /// The part was completed before, e.g. in the browser.
+    AlreadySolved,
+    /// Another answer was submitted recently, the server asks to wait for the given time.
+    TooRecent(Duration),
+    Unknown,
+}
+
+impl Verdict {
+    /// Parses the verdict from the response printed by `aoc submit`.
+    pub fn parse(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Verdict::Correct
+        } else if response.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if response.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else if response.contains("You gave an answer too recently") {
+            Verdict::TooRecent(parse_wait(response).unwrap_or(Duration::from_secs(60)))
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Whether the server accepted the answer for this part at some point.
+    pub fn is_accepted(self) -> bool {
+        matches!(self, Verdict::Correct | Verdict::AlreadySolved)
+    }
+
+    fn key(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect => "incorrect",
+            Verdict::AlreadySolved => "already_solved",
+            Verdict::TooRecent(_) => "too_recent",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::TooRecent(wait) => write!(f, "too recent, wait {}s", wait.as_secs()),
+            Verdict::Unknown => write!(f, "unknown response"),
+        }
+    }
+}
+
+/// Parses the cooldown from e.g. "You have 1m 4s left to wait."
+fn parse_wait(response: &str) -> Option<Duration> {
+    let (_, rest) = response.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    wait.split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// A single submission.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+/// All submissions, in the order they were made.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Ledger {
+    pub data: Vec<Submission>,
+}
+
+impl Ledger {
+    /// Dehydrate the ledger to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(config::get().answers_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the ledger from a JSON file. If not present, returns an empty ledger.
+    /// Fails if the file can't be read or parsed, as storing an empty ledger would wipe it.
+    pub fn read_from_file() -> Result<Self, String> {
+        let path = config::get().answers_path();
+        match fs::read_to_string(&path) {
+            Ok(json) => Ledger::try_from(json),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(e) => Err(e.to_string()),
+        }
+        .map_err(|e| {
+            let e = e.trim_end_matches('.');
+            format!("failed to read \"{}\": {e}", path.display())
+        })
+    }
+
+    pub fn record(&mut self, day: Day, part: u8, answer: &str, verdict: Verdict) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        self.data.push(Submission {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            timestamp,
+        });
+    }
+
+    /// Final verdict recorded for this exact answer, ignoring cooldowns and unknown responses.
+    pub fn verdict_for(&self, day: Day, part: u8, answer: &str) -> Option<Verdict> {
+        self.data
+            .iter()
+            .rev()
+            .filter(|s| s.day == day && s.part == part && s.answer == answer)
+            .map(|s| s.verdict)
+            .find(|v| matches!(v, Verdict::Correct | Verdict::Incorrect))
+    }
+
+    /// Whether any answer for this part was accepted.
+    pub fn is_solved(&self, day: Day, part: u8) -> bool {
+        self.data
+            .iter()
+            .any(|s| s.day == day && s.part == part && s.verdict.is_accepted())
+    }
+
+    /// Whether the star for this part was earned. The second star of a single-part day
+    /// is awarded once every other star of the event is collected.
+    pub fn has_star(&self, event: &Event, day: Day, part: u8) -> bool {
+        if part == 2 && event.is_single_part(day) {
+            self.is_solved(day, 1)
+                && event.days().all(|day| {
+                    self.is_solved(day, 1) && (event.is_single_part(day) || self.is_solved(day, 2))
+                })
+        } else {
+            self.is_solved(day, part)
+        }
+    }
+
+    /// Number of stars earned in `event`.
+    pub fn star_count(&self, event: &Event) -> usize {
+        event
+            .days()
+            .map(|day| {
+                usize::from(self.has_star(event, day, 1))
+                    + usize::from(self.has_star(event, day, 2))
+            })
+            .sum()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Ledger> for JsonValue {
+    fn from(value: Ledger) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Ledger {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Ledger {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.key().into()),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+
+        if let Verdict::TooRecent(wait) = value.verdict {
+            #[allow(clippy::cast_precision_loss)]
+            map.insert("wait_secs".into(), JsonValue::Number(wait.as_secs() as f64));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let number = |key: &str| json.get(key).and_then(|v| v.get::<f64>().copied());
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = number("part")
+            .filter(|part| *part == 1.0 || *part == 2.0)
+            .ok_or("Expected submission.part to be 1 or 2.")? as u8;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?
+            .clone();
+
+        let verdict = match json.get("verdict").and_then(|v| v.get::<String>()) {
+            Some(v) if v == "correct" => Verdict::Correct,
+            Some(v) if v == "incorrect" => Verdict::Incorrect,
+            Some(v) if v == "already_solved" => Verdict::AlreadySolved,
+            Some(v) if v == "too_recent" => Verdict::TooRecent(Duration::from_secs(
+                number("wait_secs").unwrap_or(0.0) as u64,
+            )),
+            Some(v) if v == "unknown" => Verdict::Unknown,
+            _ => return Err("Expected submission.verdict to be a known verdict.".into()),
+        };
+
+        let timestamp =
+            number("timestamp").ok_or("Expected submission.timestamp to be a number.")? as u64;
+
+        Ok(Submission {
+            day,
+            part,
+            answer,
+            verdict,
+            timestamp,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Ledger, Verdict};
+    use crate::day;
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            Verdict::parse("That's the right answer! You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too low."),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            Verdict::parse(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ),
+            Verdict::AlreadySolved
+        );
+        assert_eq!(
+            Verdict::parse("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 4s left to wait."),
+            Verdict::TooRecent(Duration::from_secs(64))
+        );
+        assert_eq!(
+            Verdict::parse("You gave an answer too recently. You have 34s left to wait."),
+            Verdict::TooRecent(Duration::from_secs(34))
+        );
+        assert_eq!(Verdict::parse("<html>"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let mut ledger = Ledger::default();
+        ledger.record(day!(1), 1, "42", Verdict::TooRecent(Duration::from_secs(5)));
+        ledger.record(day!(1), 1, "42", Verdict::Correct);
+
+        let json = tinyjson::JsonValue::from(ledger.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Ledger::try_from(json).unwrap();
+
+        assert_eq!(parsed.data, ledger.data);
+    }
+
+    #[test]
+    fn looks_up_final_verdicts() {
+        let mut ledger = Ledger::default();
+        ledger.record(day!(1), 1, "41", Verdict::Incorrect);
+        ledger.record(day!(1), 1, "42", Verdict::TooRecent(Duration::from_secs(5)));
+
+        assert_eq!(
+            ledger.verdict_for(day!(1), 1, "41"),
+            Some(Verdict::Incorrect)
+        );
+        assert_eq!(ledger.verdict_for(day!(1), 1, "42"), None);
+        assert!(!ledger.is_solved(day!(1), 1));
+
+        ledger.record(day!(1), 1, "42", Verdict::Correct);
+        assert_eq!(ledger.verdict_for(day!(1), 1, "42"), Some(Verdict::Correct));
+        assert!(ledger.is_solved(day!(1), 1));
+    }
+}
@@ -1,10 +1,13 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
+    env,
     fmt::Display,
+    fs,
+    path::PathBuf,
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::{config, Day};
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -77,22 +80,65 @@ pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandErro
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+
+    // the verdict is only printed, so capture it to let the caller inspect it.
+    let output = Command::new("aoc")
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
+}
+
+/// Session cookie used by aoc-cli, from `ADVENT_OF_CODE_SESSION` or `~/.adventofcode.session`.
+pub fn session_cookie() -> Result<String, String> {
+    let cookie = match env::var("ADVENT_OF_CODE_SESSION") {
+        Ok(cookie) => cookie,
+        Err(_) => {
+            let home = env::var_os("HOME")
+                .or_else(|| env::var_os("USERPROFILE"))
+                .ok_or("could not find the home directory")?;
+            let path = PathBuf::from(home).join(".adventofcode.session");
+            fs::read_to_string(&path).map_err(|e| {
+                format!(
+                    "failed to read session cookie from \"{}\": {e}",
+                    path.display()
+                )
+            })?
+        }
+    };
+
+    let cookie = cookie.trim();
+    if cookie.is_empty() || !cookie.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err("the session cookie is empty or malformed".into());
+    }
+    Ok(cookie.to_string())
 }
 
 fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+    config::get()
+        .day_path("inputs", day, "txt")
+        .display()
+        .to_string()
 }
 
 fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+    config::get()
+        .day_path("puzzles", day, "md")
+        .display()
+        .to_string()
 }
 
 fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+    config::get().year
 }
 
 fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
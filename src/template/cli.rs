@@ -0,0 +1,928 @@
+/// Describes every command and flag of the template in one table.
+/// The table drives `--help` output, usage errors with suggestions and shell completion scripts.
+use std::fmt::{Display, Write};
+
+use crate::template::all_days;
+
+#[derive(Debug)]
+pub struct Command {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub args: &'static [Arg],
+    pub flags: &'static [Flag],
+}
+
+/// A positional argument.
+#[derive(Debug)]
+pub struct Arg {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub required: bool,
+    pub values: Values,
+}
+
+#[derive(Debug)]
+pub struct Flag {
+    pub name: &'static str,
+    pub short: Option<&'static str>,
+    /// Placeholder for the flag's value, `None` for boolean flags.
+    pub value: Option<&'static str>,
+    pub about: &'static str,
+    pub values: Values,
+}
+
+/// Values offered by shell completions.
+#[derive(Clone, Copy, Debug)]
+pub enum Values {
+    None,
+    Days,
+    Files,
+    List(&'static [&'static str]),
+}
+
+const DAY: Arg = Arg {
+    name: "day",
+    about: "Day number, e.g. `1` or `01`.",
+    required: true,
+    values: Values::Days,
+};
+
+const fn flag(name: &'static str, about: &'static str) -> Flag {
+    Flag {
+        name,
+        short: None,
+        value: None,
+        about,
+        values: Values::None,
+    }
+}
+
+const fn option(
+    name: &'static str,
+    value: &'static str,
+    about: &'static str,
+    values: Values,
+) -> Flag {
+    Flag {
+        name,
+        short: None,
+        value: Some(value),
+        about,
+        values,
+    }
+}
+
+// flags customizing the benchmark table in the readme.
+const COLUMNS: Flag = option(
+    "--columns",
+    "list",
+    "Extra benchmark columns, comma-separated from `title`, `parse`, `total` and `share`.",
+    Values::None,
+);
+const SORT: Flag = option(
+    "--sort",
+    "order",
+    "Order of the benchmark table.",
+    Values::List(&["day", "fastest", "slowest"]),
+);
+const UNIT: Flag = option(
+    "--unit",
+    "unit",
+    "Convert all benchmark timings to one unit.",
+    Values::List(&["auto", "ns", "us", "ms", "s"]),
+);
+const HIGHLIGHT: Flag = flag("--highlight", "Mark the fastest and slowest day.");
+
+/// Flags accepted by every command.
+pub static GLOBAL_FLAGS: &[Flag] = &[
+    option(
+        "--config",
+        "path",
+        "Read defaults from this file instead of `aoc.toml`.",
+        Values::Files,
+    ),
+    option(
+        "--year",
+        "year",
+        "Override the event year from `aoc.toml`.",
+        Values::None,
+    ),
+    option(
+        "--data-dir",
+        "path",
+        "Override the data folder from `aoc.toml`.",
+        Values::Files,
+    ),
+    Flag {
+        name: "--help",
+        short: Some("-h"),
+        value: None,
+        about: "Print help.",
+        values: Values::None,
+    },
+];
+
+pub static COMMANDS: &[Command] = &[
+    Command {
+        name: "scaffold",
+        about: "Create the solution module, input and example files for a day.",
+        args: &[DAY],
+        flags: &[
+            flag("--download", "Download the input and puzzle afterwards."),
+            flag("--overwrite", "Overwrite an existing solution module."),
+        ],
+    },
+    Command {
+        name: "download",
+        about: "Download the input and puzzle description for a day via aoc-cli.",
+        args: &[DAY],
+        flags: &[],
+    },
+    Command {
+        name: "read",
+        about: "Print the puzzle description for a day via aoc-cli.",
+        args: &[DAY],
+        flags: &[],
+    },
+    Command {
+        name: "solve",
+        about: "Run the solution for a day against its real input.",
+        args: &[DAY],
+        flags: &[
+            flag("--release", "Use an optimized build."),
+            flag("--dhat", "Profile heap allocations with DHAT."),
+            flag(
+                "--spans",
+                "Use an optimized build that prints the time spent in each `span!`.",
+            ),
+            flag(
+                "--fetch",
+                "Download a missing input via aoc-cli, even if `auto_fetch` is off.",
+            ),
+            flag(
+                "--no-fetch",
+                "Don't download a missing input, even if `auto_fetch` is on.",
+            ),
+            option(
+                "--viz",
+                "dir",
+                "Record the frames of each part and write them to this folder, e.g. `14-2.gif`.",
+                Values::Files,
+            ),
+            option(
+                "--viz-format",
+                "format",
+                "Export the frames as a `term` replay script, `ppm` or `png` images, or a `gif`. Defaults to `gif`.",
+                Values::List(&["term", "ppm", "png", "gif"]),
+            ),
+            option(
+                "--submit",
+                "part",
+                "Submit the answer for a part via aoc-cli, `auto` submits both in order.",
+                Values::List(&["1", "2", "auto"]),
+            ),
+            option(
+                "--timeout",
+                "secs",
+                "Kill a solution after this many seconds.",
+                Values::None,
+            ),
+        ],
+    },
+    Command {
+        name: "scale",
+        about: "Time a solution on inputs of increasing size and fit how its time grows.",
+        args: &[DAY],
+        flags: &[
+            option(
+                "--part",
+                "part",
+                "Only analyze this part.",
+                Values::List(&["1", "2"]),
+            ),
+            option(
+                "--steps",
+                "count",
+                "Number of input sizes, each half the size of the next. Defaults to 6.",
+                Values::None,
+            ),
+            option(
+                "--max",
+                "size",
+                "Size of the largest input in records, defaults to the size of the real input.",
+                Values::None,
+            ),
+            option(
+                "--timeout",
+                "secs",
+                "Kill the solution after this many seconds.",
+                Values::None,
+            ),
+        ],
+    },
+    Command {
+        name: "fuzz-day",
+        about: "Run a solution on generated inputs and report panics with a minimized input.",
+        args: &[DAY],
+        flags: &[
+            option(
+                "--seed",
+                "seed",
+                "Seed of the first input, the next inputs use the following seeds. Random by default.",
+                Values::None,
+            ),
+            option(
+                "--count",
+                "count",
+                "Number of inputs to run. Defaults to 100.",
+                Values::None,
+            ),
+            option(
+                "--size",
+                "size",
+                "Size of each input in records. Defaults to 20.",
+                Values::None,
+            ),
+            flag(
+                "--save",
+                "Save minimized failing inputs to `data/regressions` as regression examples.",
+            ),
+            option(
+                "--timeout",
+                "secs",
+                "Kill the solution after this many seconds.",
+                Values::None,
+            ),
+        ],
+    },
+    Command {
+        name: "snapshots",
+        about: "Run the solution tests and check their snapshot assertions.",
+        args: &[Arg {
+            required: false,
+            ..DAY
+        }],
+        flags: &[flag(
+            "--update-snapshots",
+            "Write the actual outputs to `data/snapshots` instead of comparing them.",
+        )],
+    },
+    Command {
+        name: "all",
+        about: "Run the solutions for all days.",
+        args: &[],
+        flags: &[
+            flag("--release", "Use an optimized build."),
+            option(
+                "--timeout",
+                "secs",
+                "Kill a solution after this many seconds.",
+                Values::None,
+            ),
+        ],
+    },
+    Command {
+        name: "time",
+        about: "Benchmark solutions. Without arguments, only days without stored timings are run.",
+        args: &[Arg {
+            required: false,
+            ..DAY
+        }],
+        flags: &[
+            flag("--all", "Benchmark all days."),
+            flag(
+                "--store",
+                "Store timings in `timings.json` and update the README.",
+            ),
+            flag(
+                "--matrix",
+                "Compare timings with the `fast-hash` and `bump-alloc` features.",
+            ),
+            flag(
+                "--profiles",
+                "Compare timings of the release, LTO, native CPU and opt-level 2 builds.",
+            ),
+            option(
+                "--timeout",
+                "secs",
+                "Kill a solution after this many seconds.",
+                Values::None,
+            ),
+            option(
+                "--export",
+                "format",
+                "Print stored timings as `csv`, `json` or `markdown` instead of benchmarking.",
+                Values::List(&["csv", "json", "markdown"]),
+            ),
+            option(
+                "--output",
+                "path",
+                "Write the export to this file instead of stdout.",
+                Values::Files,
+            ),
+            COLUMNS,
+            SORT,
+            UNIT,
+            HIGHLIGHT,
+        ],
+    },
+    Command {
+        name: "stats",
+        about: "Show time to each star, wrong attempts and streaks per day.",
+        args: &[],
+        flags: &[],
+    },
+    Command {
+        name: "readme",
+        about:
+            "Regenerate the readme sections configured in `aoc.toml`, e.g. stars and benchmarks.",
+        args: &[],
+        flags: &[COLUMNS, SORT, UNIT, HIGHLIGHT],
+    },
+    Command {
+        name: "report",
+        about: "Write a self-contained HTML report of benchmarks and stars.",
+        args: &[],
+        flags: &[option(
+            "--output",
+            "path",
+            "Write the report to this file instead of `report.html` in the data folder.",
+            Values::Files,
+        )],
+    },
+    Command {
+        name: "leaderboard",
+        about: "Show standings and star times of the private leaderboard from `aoc.toml`.",
+        args: &[],
+        flags: &[
+            option(
+                "--file",
+                "path",
+                "Read the leaderboard JSON from this file instead of fetching it.",
+                Values::Files,
+            ),
+            option(
+                "--day",
+                "day",
+                "Only show star times for this day.",
+                Values::Days,
+            ),
+        ],
+    },
+    Command {
+        name: "today",
+        about: "Scaffold, download and read the current day. Requires the `today` feature.",
+        args: &[],
+        flags: &[flag(
+            "--wait",
+            "Wait for the next puzzle to unlock if none unlocked today.",
+        )],
+    },
+    Command {
+        name: "completions",
+        about: "Print a shell completion script for the cargo aliases.",
+        args: &[Arg {
+            name: "shell",
+            about: "One of `bash`, `zsh` or `fish`.",
+            required: true,
+            values: Values::List(SHELLS),
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "help",
+        about: "Print help for the template or a command.",
+        args: &[Arg {
+            name: "command",
+            about: "Command to print help for.",
+            required: false,
+            values: Values::None,
+        }],
+        flags: &[],
+    },
+];
+
+pub static SHELLS: &[&str] = &["bash", "zsh", "fish"];
+
+/// Looks up a command by name.
+pub fn find(name: &str) -> Option<&'static Command> {
+    COMMANDS.iter().find(|c| c.name == name)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An invalid command line, rendered together with a hint on how to fix it.
+#[derive(Debug)]
+pub struct UsageError {
+    pub message: String,
+    pub command: Option<&'static Command>,
+}
+
+impl UsageError {
+    pub fn new(message: impl Into<String>, command: Option<&'static Command>) -> Self {
+        Self {
+            message: message.into(),
+            command,
+        }
+    }
+}
+
+impl std::error::Error for UsageError {}
+
+impl Display for UsageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.message)?;
+        writeln!(f)?;
+        match self.command {
+            Some(command) => {
+                writeln!(f, "{}", usage(command))?;
+                write!(
+                    f,
+                    "For more information, try `cargo {} --help`.",
+                    command.name
+                )
+            }
+            None => {
+                writeln!(f, "Available commands: {}.", command_names().join(", "))?;
+                write!(f, "For more information, try `cargo help`.")
+            }
+        }
+    }
+}
+
+/// Resolves a command name, suggesting the closest match for typos.
+pub fn parse_command(name: &str) -> Result<&'static Command, UsageError> {
+    find(name).ok_or_else(|| {
+        let message = match suggest(name, COMMANDS.iter().map(|c| c.name)) {
+            Some(suggestion) => {
+                format!("unknown command `{name}`. Did you mean `{suggestion}`?")
+            }
+            None => format!("unknown command `{name}`."),
+        };
+        UsageError::new(message, None)
+    })
+}
+
+/// Checks that every flag passed to `command` exists, suggesting the closest match for typos.
+pub fn check_flags(command: &'static Command, args: &[String]) -> Result<(), UsageError> {
+    let known = || command.flags.iter().chain(GLOBAL_FLAGS);
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if !arg.starts_with('-') || arg == "-" {
+            continue;
+        }
+
+        let name = arg.split_once('=').map_or(arg.as_str(), |(name, _)| name);
+
+        match known().find(|f| f.name == name || f.short == Some(name)) {
+            Some(flag) => {
+                // skip the value of `--flag value`.
+                if flag.value.is_some() && !arg.contains('=') {
+                    iter.next();
+                }
+            }
+            None => {
+                let message = match suggest(name, known().map(|f| f.name)) {
+                    Some(suggestion) => format!(
+                        "unknown flag `{name}` for `{}`. Did you mean `{suggestion}`?",
+                        command.name
+                    ),
+                    None => format!("unknown flag `{name}` for `{}`.", command.name),
+                };
+                return Err(UsageError::new(message, Some(command)));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the candidate closest to `input`, if it is close enough to be a likely typo.
+pub fn suggest<'a>(input: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .map(|candidate| (edit_distance(input, candidate), candidate))
+        .filter(|(distance, candidate)| {
+            *distance <= 2.max(candidate.len() / 3) && *distance < candidate.len()
+        })
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+
+    row[b.len()]
+}
+
+fn command_names() -> Vec<&'static str> {
+    COMMANDS.iter().map(|c| c.name).collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn flag_label(flag: &Flag) -> String {
+    let name = match flag.short {
+        Some(short) => format!("{short}, {}", flag.name),
+        None => flag.name.to_string(),
+    };
+    match flag.value {
+        Some(value) => format!("{name} <{value}>"),
+        None => name,
+    }
+}
+
+fn arg_label(arg: &Arg) -> String {
+    if arg.required {
+        format!("<{}>", arg.name)
+    } else {
+        format!("[{}]", arg.name)
+    }
+}
+
+/// One-line usage of a command, e.g. `Usage: cargo solve <day> [options]`.
+pub fn usage(command: &Command) -> String {
+    let mut line = format!("Usage: cargo {}", command.name);
+    for arg in command.args {
+        line.push(' ');
+        line.push_str(&arg_label(arg));
+    }
+    line.push_str(" [options]");
+    line
+}
+
+fn write_rows(out: &mut String, title: &str, rows: &[(String, &str)]) {
+    if rows.is_empty() {
+        return;
+    }
+    let width = rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+    let _ = writeln!(out, "\n{title}:");
+    for (label, about) in rows {
+        let _ = writeln!(out, "  {label:width$}  {about}");
+    }
+}
+
+/// Full help text of a command.
+pub fn help(command: &Command) -> String {
+    let mut out = format!("{}\n\n{}\n", command.about, usage(command));
+
+    let args: Vec<_> = command
+        .args
+        .iter()
+        .map(|a| (arg_label(a), a.about))
+        .collect();
+    write_rows(&mut out, "Arguments", &args);
+
+    let flags: Vec<_> = command
+        .flags
+        .iter()
+        .map(|f| (flag_label(f), f.about))
+        .collect();
+    write_rows(&mut out, "Options", &flags);
+
+    let global: Vec<_> = GLOBAL_FLAGS
+        .iter()
+        .map(|f| (flag_label(f), f.about))
+        .collect();
+    write_rows(&mut out, "Global options", &global);
+
+    out
+}
+
+/// Overview of all commands.
+pub fn overview() -> String {
+    let mut out = String::from(
+        "Advent of Code solutions in Rust.\n\nUsage: cargo <command> [arguments] [options]\n",
+    );
+
+    let commands: Vec<_> = COMMANDS
+        .iter()
+        .map(|c| (c.name.to_string(), c.about))
+        .collect();
+    write_rows(&mut out, "Commands", &commands);
+
+    let global: Vec<_> = GLOBAL_FLAGS
+        .iter()
+        .map(|f| (flag_label(f), f.about))
+        .collect();
+    write_rows(&mut out, "Global options", &global);
+
+    out.push_str("\nSee `cargo <command> --help` for details on a command.\n");
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn value_words(values: Values) -> Vec<String> {
+    match values {
+        Values::None | Values::Files => vec![],
+        Values::Days => all_days().map(|d| d.to_string()).collect(),
+        Values::List(list) => list.iter().map(|s| (*s).to_string()).collect(),
+    }
+}
+
+fn command_flags(command: &Command) -> impl Iterator<Item = &Flag> {
+    command.flags.iter().chain(GLOBAL_FLAGS)
+}
+
+/// Escapes single quotes for use inside a single-quoted shell string.
+fn quote(s: &str) -> String {
+    s.replace('\'', "'\\''")
+}
+
+/// Generates a completion script for `cargo <command>` in the given shell.
+/// Completions for cargo's own subcommands are delegated to the shell's existing cargo completion.
+pub fn completions(shell: &str) -> Option<String> {
+    match shell {
+        "bash" => Some(bash_completions()),
+        "zsh" => Some(zsh_completions()),
+        "fish" => Some(fish_completions()),
+        _ => None,
+    }
+}
+
+fn bash_completions() -> String {
+    let mut out = String::new();
+    let names = command_names().join(" ");
+
+    let _ = writeln!(
+        out,
+        r#"# bash completion for the advent of code cargo aliases.
+# usage: source <(cargo completions bash)
+_aoc_cargo_fallback="$(complete -p cargo 2>/dev/null | sed -n 's/.*-F \([^ ]*\).*/\1/p')"
+
+_aoc_cargo() {{
+    local cur="${{COMP_WORDS[COMP_CWORD]}}"
+    local prev="${{COMP_WORDS[COMP_CWORD-1]}}"
+    local words=""
+
+    if [[ $COMP_CWORD -eq 1 ]]; then
+        COMPREPLY=($(compgen -W "{names}" -- "$cur"))
+        if [[ -n $_aoc_cargo_fallback ]]; then
+            local ours=("${{COMPREPLY[@]}}")
+            "$_aoc_cargo_fallback" "$@"
+            COMPREPLY+=("${{ours[@]}}")
+        fi
+        return
+    fi
+
+    case "${{COMP_WORDS[1]}}" in"#
+    );
+
+    for command in COMMANDS {
+        let _ = writeln!(out, "        {})", command.name);
+        let _ = writeln!(out, "            case \"$prev\" in");
+        for flag in command_flags(command).filter(|f| f.value.is_some()) {
+            let action = match flag.values {
+                Values::Files => "COMPREPLY=($(compgen -f -- \"$cur\")); return".to_string(),
+                values => format!(
+                    "COMPREPLY=($(compgen -W \"{}\" -- \"$cur\")); return",
+                    value_words(values).join(" ")
+                ),
+            };
+            let _ = writeln!(out, "                {}) {action};;", flag.name);
+        }
+        let _ = writeln!(out, "            esac");
+
+        let mut words: Vec<String> = command_flags(command).map(|f| f.name.to_string()).collect();
+        for arg in command.args {
+            words.extend(value_words(arg.values));
+        }
+        let _ = writeln!(out, "            words=\"{}\"", words.join(" "));
+        let _ = writeln!(out, "            ;;");
+    }
+
+    let _ = writeln!(
+        out,
+        r#"        *)
+            [[ -n $_aoc_cargo_fallback ]] && "$_aoc_cargo_fallback" "$@"
+            return
+            ;;
+    esac
+
+    COMPREPLY=($(compgen -W "$words" -- "$cur"))
+}}
+
+complete -F _aoc_cargo cargo"#
+    );
+
+    out
+}
+
+fn zsh_flag_spec(flag: &Flag) -> String {
+    let about = quote(&flag.about.replace('[', "(").replace(']', ")"));
+    match (flag.value, flag.values) {
+        (None, _) => format!("'{}[{about}]'", flag.name),
+        (Some(value), Values::Files) => format!("'{}[{about}]:{value}:_files'", flag.name),
+        (Some(value), values) => format!(
+            "'{}[{about}]:{value}:({})'",
+            flag.name,
+            value_words(values).join(" ")
+        ),
+    }
+}
+
+fn zsh_completions() -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(
+        out,
+        r#"#compdef cargo
+# zsh completion for the advent of code cargo aliases.
+# usage: source <(cargo completions zsh)
+_aoc_cargo() {{
+    if (( CURRENT == 2 )); then
+        local -a commands
+        commands=("#
+    );
+
+    for command in COMMANDS {
+        let _ = writeln!(
+            out,
+            "            '{}:{}'",
+            command.name,
+            quote(&command.about.replace(':', "\\:"))
+        );
+    }
+
+    let _ = writeln!(
+        out,
+        r#"        )
+        _describe -t aoc-commands 'advent of code command' commands
+        (( $+functions[_cargo] )) && _cargo
+        return
+    fi
+
+    case $words[2] in"#
+    );
+
+    for command in COMMANDS {
+        let mut specs: Vec<String> = command_flags(command).map(zsh_flag_spec).collect();
+        for (i, arg) in command.args.iter().enumerate() {
+            let optional = if arg.required { "" } else { ":" };
+            specs.push(format!(
+                "'{}{optional}:{}:({})'",
+                i + 1,
+                arg.name,
+                value_words(arg.values).join(" ")
+            ));
+        }
+
+        let _ = writeln!(out, "        {})", command.name);
+        let _ = writeln!(out, "            shift words; (( CURRENT-- ))");
+        let _ = writeln!(out, "            _arguments -s {}", specs.join(" "));
+        let _ = writeln!(out, "            ;;");
+    }
+
+    let _ = writeln!(
+        out,
+        r#"        *)
+            (( $+functions[_cargo] )) && _cargo
+            ;;
+    esac
+}}
+
+compdef _aoc_cargo cargo"#
+    );
+
+    out
+}
+
+fn fish_completions() -> String {
+    let mut out = String::from(
+        "# fish completion for the advent of code cargo aliases.\n# usage: cargo completions fish | source\n",
+    );
+
+    for command in COMMANDS {
+        let _ = writeln!(
+            out,
+            "complete -c cargo -n __fish_use_subcommand -f -a {} -d '{}'",
+            command.name,
+            quote(command.about)
+        );
+    }
+
+    for command in COMMANDS {
+        let condition = format!("'__fish_seen_subcommand_from {}'", command.name);
+
+        for arg in command.args {
+            let words = value_words(arg.values);
+            if !words.is_empty() {
+                let _ = writeln!(
+                    out,
+                    "complete -c cargo -n {condition} -f -a '{}' -d '{}'",
+                    words.join(" "),
+                    quote(arg.about)
+                );
+            }
+        }
+
+        for flag in command_flags(command) {
+            let mut line = format!(
+                "complete -c cargo -n {condition} -l {}",
+                flag.name.trim_start_matches("--")
+            );
+            if let Some(short) = flag.short {
+                let _ = write!(line, " -s {}", short.trim_start_matches('-'));
+            }
+            match (flag.value, flag.values) {
+                (None, _) => {}
+                (Some(_), Values::Files) => line.push_str(" -r -F"),
+                (Some(_), values) => {
+                    let _ = write!(line, " -x -a '{}'", value_words(values).join(" "));
+                }
+            }
+            let _ = writeln!(out, "{line} -d '{}'", quote(flag.about));
+        }
+    }
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check_flags, completions, find, help, parse_command, suggest, COMMANDS};
+
+    #[test]
+    fn suggests_close_matches() {
+        let names = || COMMANDS.iter().map(|c| c.name);
+        assert_eq!(suggest("slove", names()), Some("solve"));
+        assert_eq!(suggest("tme", names()), Some("time"));
+        assert_eq!(suggest("downlaod", names()), Some("download"));
+        assert_eq!(suggest("xyz", names()), None);
+    }
+
+    #[test]
+    fn reports_unknown_commands() {
+        let err = parse_command("scafold").unwrap_err();
+        assert_eq!(
+            err.message,
+            "unknown command `scafold`. Did you mean `scaffold`?"
+        );
+        assert!(err.to_string().contains("Available commands: scaffold,"));
+    }
+
+    #[test]
+    fn reports_unknown_flags() {
+        let solve = find("solve").unwrap();
+        let err = check_flags(solve, &["01".into(), "--relase".into()]).unwrap_err();
+        assert_eq!(
+            err.message,
+            "unknown flag `--relase` for `solve`. Did you mean `--release`?"
+        );
+    }
+
+    #[test]
+    fn accepts_known_and_global_flags() {
+        let solve = find("solve").unwrap();
+        let args = [
+            "01".into(),
+            "--submit".into(),
+            "1".into(),
+            "--year=2023".into(),
+            "-h".into(),
+        ];
+        assert!(check_flags(solve, &args).is_ok());
+    }
+
+    #[test]
+    fn renders_help() {
+        let text = help(find("time").unwrap());
+        assert!(text.contains("Usage: cargo time [day] [options]"));
+        assert!(text.contains("  --store "));
+        assert!(text.contains("Global options:"));
+    }
+
+    #[test]
+    fn renders_completions() {
+        let bash = completions("bash").unwrap();
+        assert!(bash.contains("complete -F _aoc_cargo cargo"));
+        assert!(bash.contains("--submit) COMPREPLY=($(compgen -W \"1 2 auto\""));
+
+        let zsh = completions("zsh").unwrap();
+        assert!(zsh.contains("compdef _aoc_cargo cargo"));
+
+        let fish = completions("fish").unwrap();
+        assert!(fish.contains("-n '__fish_seen_subcommand_from solve' -l release"));
+
+        assert!(completions("powershell").is_none());
+    }
+}
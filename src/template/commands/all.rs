@@ -1,5 +1,14 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::time::Duration;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::run_multi::{child_commands::Profile, run_multi};
+use crate::template::{all_days, Status};
+
+pub fn handle(is_release: bool, timeout: Option<Duration>) -> Status {
+    let profile = if is_release {
+        Profile::Release
+    } else {
+        Profile::Debug
+    };
+    let (_, status) = run_multi(&all_days().collect(), profile, false, timeout);
+    status
 }
@@ -1,14 +1,17 @@
-use crate::template::{aoc_cli, Day};
-use std::process;
+use crate::template::stats::{self, Milestone};
+use crate::template::{aoc_cli, Day, Status};
 
-pub fn handle(day: Day) {
+pub fn handle(day: Day) -> Status {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+        return Status::Failure;
     }
 
     if let Err(e) = aoc_cli::download(day) {
         eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+        return Status::Failure;
     };
+
+    stats::track(day, Milestone::Downloaded);
+    Status::Success
 }
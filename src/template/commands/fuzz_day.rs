@@ -0,0 +1,24 @@
+use std::time::Duration;
+
+use crate::template::fuzz::FuzzOptions;
+use crate::template::run_multi::child_commands::{run_interactive, Profile};
+use crate::template::{Day, Status};
+
+/// Runs the day on generated inputs. Uses a debug build, so arithmetic overflows panic as well.
+pub fn handle(day: Day, options: FuzzOptions, timeout: Option<Duration>) -> Status {
+    let mut cmd_args = vec![
+        "--fuzz".to_string(),
+        "--seed".to_string(),
+        options.seed.to_string(),
+        "--count".to_string(),
+        options.count.to_string(),
+        "--size".to_string(),
+        options.size.to_string(),
+    ];
+
+    if options.save {
+        cmd_args.push("--save".to_string());
+    }
+
+    run_interactive(day, Profile::Debug, &cmd_args, timeout)
+}
@@ -0,0 +1,120 @@
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::{Duration, SystemTime},
+};
+
+use crate::template::aoc_cli;
+use crate::template::leaderboard::Leaderboard;
+use crate::template::{config, Day, Status};
+
+/// The puzzle server asks clients not to request leaderboards more often than this.
+const CACHE_DURATION: Duration = Duration::from_secs(15 * 60);
+
+pub fn handle(file: Option<PathBuf>, day: Option<Day>) -> Status {
+    let leaderboard = match file {
+        Some(path) => fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read \"{}\": {e}", path.display()))
+            .and_then(parse),
+        None => fetch_online(),
+    };
+
+    let leaderboard = match leaderboard {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            eprintln!("{e}");
+            return Status::Failure;
+        }
+    };
+
+    let event = match leaderboard.event.parse() {
+        Ok(year) => config::get().event(year),
+        Err(_) => config::get().current_event(),
+    };
+
+    if let Some(day) = day {
+        match leaderboard.render_day(day) {
+            Some(rendered) => print!("{rendered}"),
+            None => println!("Nobody has a star for day {day} yet."),
+        }
+        return Status::Success;
+    }
+
+    println!("Private leaderboard of the {} event", leaderboard.event);
+    println!();
+    print!("{}", leaderboard.render_standings(&event));
+    for day in event.days() {
+        if let Some(rendered) = leaderboard.render_day(day) {
+            println!();
+            print!("{rendered}");
+        }
+    }
+
+    Status::Success
+}
+
+fn parse(json: String) -> Result<Leaderboard, String> {
+    Leaderboard::try_from(json).map_err(|e| format!("Failed to parse leaderboard: {e}"))
+}
+
+/// Fetches the leaderboard from `aoc.toml`, reusing a cached copy younger than [`CACHE_DURATION`].
+fn fetch_online() -> Result<Leaderboard, String> {
+    let config = config::get();
+    let Some(id) = config.leaderboard.id else {
+        return Err("No leaderboard configured. Set `id` in the `[leaderboard]` table of `aoc.toml` or pass `--file <path>`.".into());
+    };
+    let year = config.current_event().year;
+
+    let cache_path = config.leaderboard_path(year, id);
+    if is_fresh(&cache_path) {
+        if let Ok(json) = fs::read_to_string(&cache_path) {
+            return parse(json);
+        }
+    }
+
+    let url = format!("https://adventofcode.com/{year}/leaderboard/private/view/{id}.json");
+    let json = download(&url).map_err(|e| format!("Failed to fetch {url}: {e}."))?;
+    // only cache a valid leaderboard, an expired session cookie gets a login page instead.
+    let leaderboard = parse(json.clone())?;
+    if let Err(e) = fs::write(&cache_path, json) {
+        eprintln!("Failed to cache leaderboard: {e}");
+    }
+    Ok(leaderboard)
+}
+
+/// Downloads `url` with curl and the session cookie of aoc-cli.
+fn download(url: &str) -> Result<String, String> {
+    let cookie = aoc_cli::session_cookie()?;
+
+    // the cookie is passed in a config on stdin, so it does not show up in the process list.
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--config", "-", url])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|_| "command \"curl\" not found or not callable")?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        writeln!(stdin, "header = \"Cookie: session={cookie}\"")
+            .map_err(|e| format!("failed to pass the session cookie to curl: {e}"))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("failed to wait for curl: {e}"))?;
+    if !output.status.success() {
+        return Err("curl exited with a non-zero status".into());
+    }
+    String::from_utf8(output.stdout).map_err(|_| "the response is not valid UTF-8".into())
+}
+
+fn is_fresh(path: &Path) -> bool {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < CACHE_DURATION)
+}
@@ -1,6 +1,15 @@
 pub mod all;
 pub mod download;
+pub mod fuzz_day;
+pub mod leaderboard;
 pub mod read;
+pub mod readme;
+pub mod report;
 pub mod scaffold;
+pub mod scale;
+pub mod snapshots;
 pub mod solve;
+pub mod stats;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
@@ -1,15 +1,15 @@
-use std::process;
+use crate::template::{aoc_cli, Day, Status};
 
-use crate::template::{aoc_cli, Day};
-
-pub fn handle(day: Day) {
+pub fn handle(day: Day) -> Status {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+        return Status::Failure;
     }
 
     if let Err(e) = aoc_cli::read(day) {
         eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+        return Status::Failure;
     };
+
+    Status::Success
 }
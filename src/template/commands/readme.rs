@@ -0,0 +1,21 @@
+use crate::template::readme::{self, Section};
+use crate::template::readme_benchmarks::TableOptions;
+use crate::template::Status;
+
+pub fn handle(table: &TableOptions) -> Status {
+    match readme::update(&Section::ALL, table) {
+        Ok(updated) if updated.is_empty() => {
+            println!("No readme sections configured in `aoc.toml`.");
+            Status::Success
+        }
+        Ok(updated) => {
+            let names: Vec<_> = updated.iter().map(|s| s.name()).collect();
+            println!("Updated readme sections: {}.", names.join(", "));
+            Status::Success
+        }
+        Err(e) => {
+            eprintln!("Failed to update readme: {e}");
+            Status::Failure
+        }
+    }
+}
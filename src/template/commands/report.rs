@@ -0,0 +1,40 @@
+use std::{fs, path::PathBuf};
+
+use crate::template::answers::Ledger;
+use crate::template::readme::read_titles;
+use crate::template::report::Report;
+use crate::template::timings::{History, Timings};
+use crate::template::{config, Status};
+
+pub fn handle(output: PathBuf) -> Status {
+    let ledger = match Ledger::read_from_file() {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("Failed to build the report, {e}.");
+            return Status::Failure;
+        }
+    };
+    let event = config::get().current_event();
+    let timings = Timings::read_from_file();
+    let titles = read_titles(&event.days().collect::<Vec<_>>());
+
+    let html = Report {
+        event: &event,
+        timings: &timings,
+        ledger: &ledger,
+        history: &History::read_from_file(),
+        titles: &titles,
+    }
+    .render();
+
+    match fs::write(&output, html) {
+        Ok(()) => {
+            println!("🎄 Wrote report to \"{}\".", output.display());
+            Status::Success
+        }
+        Err(e) => {
+            eprintln!("Failed to write report \"{}\": {e}", output.display());
+            Status::Failure
+        }
+    }
+}
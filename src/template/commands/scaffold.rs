@@ -1,15 +1,26 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
-    process,
+    path::Path,
 };
 
-use crate::template::Day;
+use crate::template::stats::{self, Milestone};
+use crate::template::{config, Day, Status};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
+/// Template for days without a second part, e.g. the last day of an event.
+const SINGLE_PART_MODULE_TEMPLATE: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/src/template_single_part.txt"
+));
+
+/// Lines between these markers of a custom template are left out for single-part days.
+const PART_TWO_START: &str = "%PART_TWO_START%";
+const PART_TWO_END: &str = "%PART_TWO_END%";
+
+fn safe_create_file(path: &Path, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
         file.create(true);
@@ -19,7 +30,7 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new()
         .write(true)
         .create(true)
@@ -27,21 +38,115 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+fn read_template(single_part: bool) -> Result<String, Status> {
+    let Some(path) = &config::get().scaffold.template else {
+        return Ok(if single_part {
+            SINGLE_PART_MODULE_TEMPLATE.to_string()
+        } else {
+            MODULE_TEMPLATE.to_string()
+        });
+    };
+
+    let template = fs::read_to_string(path).map_err(|e| {
+        eprintln!("Failed to read module template \"{}\": {e}", path.display());
+        Status::Failure
+    })?;
+
+    if single_part && !template.contains(PART_TWO_START) {
+        eprintln!(
+            "Module template \"{}\" has no `{PART_TWO_START}` marker, so its second part is kept for this single-part day.",
+            path.display()
+        );
+    }
+
+    strip_part_two(&template, single_part).map_err(|e| {
+        eprintln!("Invalid module template \"{}\": {e}", path.display());
+        Status::Failure
+    })
+}
+
+/// Removes the part-two markers of a custom template, and the lines between them if `single_part`.
+fn strip_part_two(template: &str, single_part: bool) -> Result<String, String> {
+    let mut result = String::with_capacity(template.len());
+    let mut part_two_start = None;
+
+    for (i, line) in template.split_inclusive('\n').enumerate() {
+        if line.contains(PART_TWO_START) {
+            if part_two_start.is_some() {
+                return Err(format!("line {}: nested `{PART_TWO_START}`.", i + 1));
+            }
+            part_two_start = Some(i + 1);
+        } else if line.contains(PART_TWO_END) {
+            if part_two_start.is_none() {
+                return Err(format!(
+                    "line {}: `{PART_TWO_END}` without `{PART_TWO_START}`.",
+                    i + 1
+                ));
+            }
+            part_two_start = None;
+        } else if !(single_part && part_two_start.is_some()) {
+            result.push_str(line);
+        }
+    }
+
+    if let Some(line) = part_two_start {
+        return Err(format!("line {line}: `{PART_TWO_START}` is never closed."));
+    }
+
+    Ok(result)
+}
+
+/// Adds `pub mod dayNN;` to `src/solutions/mod.rs` unless the module is already declared.
+fn register_module(day: Day) -> Result<bool, std::io::Error> {
+    let path = Path::new("src/solutions/mod.rs");
+    let declaration = format!("pub mod day{day};");
+    let modules = fs::read_to_string(path)?;
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
+    if modules.lines().any(|line| line.trim() == declaration) {
+        return Ok(false);
+    }
+
+    let mut file = OpenOptions::new().append(true).open(path)?;
+    if !modules.is_empty() && !modules.ends_with('\n') {
+        writeln!(file)?;
+    }
+    writeln!(file, "{declaration}")?;
+    Ok(true)
+}
+
+pub fn handle(day: Day, overwrite: bool) -> Status {
+    let config = config::get();
+    let input_path = config.day_path("inputs", day, "txt");
+    let example_path = config.day_path("examples", day, "txt");
+    let module_path = format!("src/solutions/day{day}.rs");
+    let bin_path = format!("src/bin/{day}.rs");
+    let event = config.current_event();
+    let single_part = event.is_single_part(day);
+
+    if !event.contains(day) {
+        eprintln!(
+            "Day {day} is not part of the {} event, which has {} days.",
+            event.year,
+            event.len()
+        );
+        return Status::Failure;
+    }
+
+    let template = match read_template(single_part) {
+        Ok(template) => template,
+        Err(status) => return status,
+    };
+
+    let mut file = match safe_create_file(Path::new(&module_path), overwrite) {
         Ok(file) => file,
         Err(e) => {
             eprintln!("Failed to create module file: {e}");
-            process::exit(1);
+            return Status::Failure;
         }
     };
 
     match file.write_all(
-        MODULE_TEMPLATE
+        template
             .replace("%DAY_NUMBER%", &day.into_inner().to_string())
             .as_bytes(),
     ) {
@@ -50,30 +155,101 @@ pub fn handle(day: Day, overwrite: bool) {
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+            return Status::Failure;
+        }
+    }
+
+    let bin = if single_part {
+        format!("advent_of_code::solution!(use day{day}, 1);\n")
+    } else {
+        format!("advent_of_code::solution!(use day{day});\n")
+    };
+
+    match safe_create_file(Path::new(&bin_path), overwrite)
+        .and_then(|mut file| file.write_all(bin.as_bytes()))
+    {
+        Ok(()) => {
+            println!("Created binary file \"{}\"", &bin_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to create binary file: {e}");
+            return Status::Failure;
+        }
+    }
+
+    match register_module(day) {
+        Ok(true) => {
+            println!("Added module \"day{day}\" to \"src/solutions/mod.rs\"");
+        }
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!("Failed to add module to \"src/solutions/mod.rs\": {e}");
+            return Status::Failure;
         }
     }
 
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+            return Status::Failure;
         }
     }
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+            return Status::Failure;
         }
     }
 
+    stats::track(day, Milestone::Scaffolded);
+
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
+    Status::Success
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::strip_part_two;
+
+    const TEMPLATE: &str = "fn part_one() {}\n// %PART_TWO_START%\nfn part_two() {}\n// %PART_TWO_END%\nfn main() {}\n";
+
+    #[test]
+    fn strips_part_two_for_single_part_days() {
+        assert_eq!(
+            strip_part_two(TEMPLATE, true).unwrap(),
+            "fn part_one() {}\nfn main() {}\n"
+        );
+    }
+
+    #[test]
+    fn keeps_part_two_without_markers() {
+        assert_eq!(
+            strip_part_two(TEMPLATE, false).unwrap(),
+            "fn part_one() {}\nfn part_two() {}\nfn main() {}\n"
+        );
+    }
+
+    #[test]
+    fn rejects_unbalanced_markers() {
+        assert_eq!(
+            strip_part_two("%PART_TWO_START%\n%PART_TWO_START%\n", false).unwrap_err(),
+            "line 2: nested `%PART_TWO_START%`."
+        );
+        assert_eq!(
+            strip_part_two("a\nb\n%PART_TWO_END%\n", true).unwrap_err(),
+            "line 3: `%PART_TWO_END%` without `%PART_TWO_START%`."
+        );
+        assert_eq!(
+            strip_part_two("a\n%PART_TWO_START%\nb\n", false).unwrap_err(),
+            "line 2: `%PART_TWO_START%` is never closed."
+        );
+    }
 }
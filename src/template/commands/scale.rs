@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+use crate::template::run_multi::child_commands::{run_interactive, Profile};
+use crate::template::scale::ScaleOptions;
+use crate::template::{Day, Status};
+
+pub fn handle(day: Day, options: ScaleOptions, timeout: Option<Duration>) -> Status {
+    let mut cmd_args = vec![
+        "--scale".to_string(),
+        "--steps".to_string(),
+        options.steps.to_string(),
+    ];
+
+    if let Some(max) = options.max {
+        cmd_args.push("--max".to_string());
+        cmd_args.push(max.to_string());
+    }
+
+    if let Some(part) = options.part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
+    }
+
+    run_interactive(day, Profile::Release, &cmd_args, timeout)
+}
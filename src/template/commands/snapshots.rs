@@ -0,0 +1,33 @@
+use std::process::Command;
+
+use crate::template::snapshot::UPDATE_ENV;
+use crate::template::{Day, Status};
+
+/// Runs the solution tests of `day`, or of all days, with `--update-snapshots` writing the actual
+/// outputs of snapshot assertions instead of comparing them.
+pub fn handle(day: Option<Day>, update: bool) -> Status {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["test", "--lib", "--quiet"]);
+    if let Some(day) = day {
+        cmd.arg(format!("solutions::day{day}::"));
+    } else {
+        cmd.arg("solutions::");
+    }
+    if update {
+        cmd.env(UPDATE_ENV, "1");
+    }
+
+    match cmd.status() {
+        Ok(status) if status.success() => {
+            if update {
+                println!("Snapshots in `data/snapshots` are up to date, review the changes before committing them.");
+            }
+            Status::Success
+        }
+        Ok(_) => Status::Failure,
+        Err(e) => {
+            eprintln!("Failed to run cargo test: {e}");
+            Status::Failure
+        }
+    }
+}
@@ -1,34 +1,88 @@
-use std::process::{Command, Stdio};
+use std::fs;
+use std::time::Duration;
 
-use crate::template::Day;
+use crate::template::commands::download;
+use crate::template::run_multi::child_commands::{build_solution, run_binary, Profile};
+use crate::template::runner::Submit;
+use crate::template::stats::{self, Milestone};
+use crate::template::viz::VizOptions;
+use crate::template::{aoc_cli, config, Day, Status};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    day: Day,
+    release: bool,
+    dhat: bool,
+    spans: bool,
+    fetch: bool,
+    submit: Option<Submit>,
+    viz: Option<VizOptions>,
+    timeout: Option<Duration>,
+) -> Status {
+    let status = ensure_input(day, fetch);
+    if !status.is_success() {
+        return status;
+    }
 
-    if dhat {
-        cmd_args.extend([
-            "--profile".to_string(),
-            "dhat".to_string(),
-            "--features".to_string(),
-            "dhat-heap".to_string(),
-        ]);
+    let profile = if dhat {
+        Profile::Dhat
+    } else if spans {
+        Profile::Spans
     } else if release {
-        cmd_args.push("--release".to_string());
-    }
+        Profile::Release
+    } else {
+        Profile::Debug
+    };
+
+    let binary = match build_solution(day, profile, false) {
+        Ok(binary) => binary,
+        Err(status) => return status,
+    };
+
+    stats::track(day, Milestone::FirstRun);
 
-    cmd_args.push("--".to_string());
+    let mut cmd_args = vec![];
 
-    if let Some(submit_part) = submit_part {
+    if let Some(submit) = submit {
         cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
+        cmd_args.push(submit.to_string());
     }
 
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+    if let Some(viz) = viz {
+        cmd_args.push("--viz".to_string());
+        cmd_args.push(viz.prefix.display().to_string());
+        cmd_args.push("--viz-format".to_string());
+        cmd_args.push(viz.format.to_string());
+    }
+
+    run_binary(&binary, &cmd_args, timeout)
+}
+
+/// Checks that the input for `day` exists and is not empty, downloading it via aoc-cli if `fetch`
+/// is set. Fresh clones only contain the empty input files created by `scaffold`.
+fn ensure_input(day: Day, fetch: bool) -> Status {
+    let path = config::get().day_path("inputs", day, "txt");
+
+    if fs::metadata(&path).is_ok_and(|meta| meta.len() > 0) {
+        return Status::Success;
+    }
+
+    let hint = format!(
+        "Input for day {day} is missing or empty: \"{}\".",
+        path.display()
+    );
+
+    if !fetch {
+        eprintln!("{hint} Download it with `cargo download {day}`, append `--fetch` or paste it into the file.");
+        return Status::Failure;
+    }
+
+    if aoc_cli::check().is_err() {
+        eprintln!("{hint} Install aoc-cli with \"cargo install aoc-cli\" to download it automatically, or paste it into the file.");
+        return Status::Failure;
+    }
 
-    cmd.wait().unwrap();
+    println!("{hint} Downloading it via aoc-cli...");
+    println!("Each download is a request to adventofcode.com and counts against its rate limits.");
+    download::handle(day)
 }
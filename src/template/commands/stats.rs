@@ -0,0 +1,37 @@
+use crate::template::stats::{report, Stats};
+use crate::template::{config, Status};
+
+pub fn handle() -> Status {
+    let event = config::get().current_event();
+    let stats = match Stats::read_from_file() {
+        Ok(stats) => stats,
+        Err(e) => {
+            eprintln!("Failed to show stats, {e}.");
+            return Status::Failure;
+        }
+    };
+    let reports = report(&stats, &event);
+
+    if reports.is_empty() {
+        println!(
+            "No stats recorded for the {} event yet. They are collected by `scaffold`, `download`, `solve` and `--submit`.",
+            event.year
+        );
+        return Status::Success;
+    }
+
+    println!(
+        "Day  {:<16}  {:>11}  {:>11}  {:>5}  {:>6}",
+        "Started (UTC-5)", "1st star", "2nd star", "Wrong", "Streak"
+    );
+    for report in &reports {
+        println!("{report}");
+    }
+
+    let longest = reports.iter().map(|r| r.streak).max().unwrap_or(0);
+    let current = reports.last().map_or(0, |r| r.streak);
+    println!();
+    println!("Current streak: {current} days, longest streak: {longest} days.");
+
+    Status::Success
+}
@@ -1,40 +1,206 @@
 use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
 
-use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::matrix::{self, BuildConfig, Experiment, Row};
+use crate::template::readme::{self, Section};
+use crate::template::readme_benchmarks::TableOptions;
+use crate::template::run_multi::{child_commands::Profile, run_multi};
+use crate::template::timings::{ConfigurationTimings, ExportFormat, History, Timings};
+use crate::template::{config, Day, Status, ANSI_BOLD, ANSI_RESET};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+pub fn handle(
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    timeout: Option<Duration>,
+    table: &TableOptions,
+) -> Status {
+    let (stored_timings, errors) = Timings::read_from_file_checked();
+    for e in &errors {
+        eprintln!("Skipping invalid timings: {e}");
+    }
+
+    if store {
+        if let Some(status) = refuse_partial_store(&errors) {
+            return status;
+        }
+    }
+
+    let event = config::get().current_event();
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                event.days().collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                event
+                    .days()
+                    .filter(|day| !stored_timings.is_day_complete(*day, &event))
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let (timings, status) = run_multi(&days_to_run, Profile::Release, true, timeout);
+    let Some(timings) = timings else {
+        return Status::Failure;
+    };
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        if let Err(e) = merged_timings.store_file() {
+            eprintln!("Failed to store timings: {e}");
+            return Status::Failure;
+        }
+
+        let mut history = History::read_from_file();
+        history.record(&timings);
+        if let Err(e) = history.store_file() {
+            eprintln!("Failed to store timing history: {e}");
+        }
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
+        match readme::update(&[Section::Benchmarks], table) {
+            Ok(_) => {
                 println!("Stored updated benchmarks.");
             }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+            Err(e) => {
+                eprintln!("Failed to store updated benchmarks: {e}");
+                return status.and(Status::Failure);
+            }
+        }
+    }
+
+    status
+}
+
+/// Benchmarks `day`, or every day of the event, in each named build and prints a comparison of
+/// the totals. Returns the timings of every build, in order.
+fn compare(
+    day: Option<Day>,
+    builds: &[(&str, Profile)],
+    timeout: Option<Duration>,
+) -> (Vec<Timings>, Status) {
+    let event = config::get().current_event();
+    let days_to_run: HashSet<Day> =
+        day.map_or_else(|| event.days().collect(), |day| HashSet::from([day]));
+
+    let mut rows: Vec<Row> = event
+        .days()
+        .filter(|day| days_to_run.contains(day))
+        .map(|day| (day, vec![None; builds.len()]))
+        .collect();
+    let mut all_timings = vec![];
+    let mut status = Status::Success;
+
+    for (i, (name, profile)) in builds.iter().enumerate() {
+        println!("{ANSI_BOLD}=== {name} ==={ANSI_RESET}\n");
+
+        let (timings, run_status) = run_multi(&days_to_run, *profile, true, timeout);
+        let timings = timings.unwrap_or_default();
+        status = status.and(run_status);
+        println!();
+
+        for timing in timings.data.iter().filter(|t| t.total_nanos > 0.0) {
+            if let Some((_, times)) = rows.iter_mut().find(|(day, _)| *day == timing.day) {
+                times[i] = Some(timing.total_nanos);
             }
         }
+        all_timings.push(timings);
+    }
+
+    let names: Vec<&str> = builds.iter().map(|(name, _)| *name).collect();
+    print!("{}", matrix::render(&names, &rows));
+    (all_timings, status)
+}
+
+/// Benchmarks with each [`Experiment`] and compares the totals.
+/// Nothing is stored, as the timings of the experiments are not comparable to the stored ones.
+pub fn matrix(day: Option<Day>, timeout: Option<Duration>) -> Status {
+    let builds: Vec<(&str, Profile)> = Experiment::ALL
+        .into_iter()
+        .map(|experiment| (experiment.name(), Profile::Experiment(experiment)))
+        .collect();
+    compare(day, &builds, timeout).1
+}
+
+/// Benchmarks with each [`BuildConfig`] and compares the totals.
+/// With `store`, the timings of every configuration are merged into the stored ones, see
+/// [`ConfigurationTimings`].
+pub fn profiles(day: Option<Day>, store: bool, timeout: Option<Duration>) -> Status {
+    let builds: Vec<(&str, Profile)> = BuildConfig::ALL
+        .into_iter()
+        .map(|config| (config.name(), Profile::Build(config)))
+        .collect();
+
+    let (mut configurations, errors) = ConfigurationTimings::read_from_file_checked();
+    for e in &errors {
+        eprintln!("Skipping invalid timings: {e}");
     }
+
+    if store {
+        if let Some(status) = refuse_partial_store(&errors) {
+            return status;
+        }
+    }
+
+    let (all_timings, status) = compare(day, &builds, timeout);
+
+    if !store {
+        return status;
+    }
+
+    for ((name, _), timings) in builds.iter().zip(&all_timings) {
+        configurations.merge(name, timings);
+    }
+
+    match configurations.store_file() {
+        Ok(()) => {
+            println!("\nStored timings of {} configurations.", builds.len());
+            status
+        }
+        Err(e) => {
+            eprintln!("Failed to store timings: {e}");
+            status.and(Status::Failure)
+        }
+    }
+}
+
+/// Writes the stored timings in `format` to `output`, or to stdout if no path is given.
+pub fn export(format: ExportFormat, output: Option<PathBuf>) -> Status {
+    let exported = Timings::read_from_file().export(format);
+
+    match output {
+        Some(path) => match fs::write(&path, exported) {
+            Ok(()) => {
+                println!("Exported timings to \"{}\".", path.display());
+                Status::Success
+            }
+            Err(e) => {
+                eprintln!("Failed to export timings to \"{}\": {e}", path.display());
+                Status::Failure
+            }
+        },
+        None => {
+            print!("{exported}");
+            Status::Success
+        }
+    }
+}
+
+/// Storing would drop the entries that could not be read, so leave the file for the user to fix.
+fn refuse_partial_store(errors: &[String]) -> Option<Status> {
+    if errors.is_empty() {
+        return None;
+    }
+
+    eprintln!(
+        "Refusing to overwrite \"{}\" as it could not be fully read. Fix or remove the invalid entries and run again.",
+        config::get().timings_path().display()
+    );
+    Some(Status::Failure)
 }
@@ -0,0 +1,65 @@
+use std::io::{stdout, Write};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::template::commands::{download, read, scaffold};
+use crate::template::event::format_hms;
+use crate::template::{config, Day, Status};
+
+pub fn handle(wait: bool) -> Status {
+    let event = config::get().current_event();
+    let now = SystemTime::now();
+
+    let day = match event.day_at(now) {
+        Some(day) => day,
+        None if wait => match event.next_unlock(now) {
+            Some((day, unlock)) => {
+                countdown(day, unlock);
+                day
+            }
+            None => {
+                eprintln!(
+                    "The {} event is over, its last puzzle unlocked on {}. Please use `scaffold` with a specific day.",
+                    event.year, event.end
+                );
+                return Status::Failure;
+            }
+        },
+        None => {
+            eprintln!(
+                "`today` command can only be run between {} and {}. Append `--wait` to wait for the next puzzle, or use `scaffold` with a specific day.",
+                event.start, event.end
+            );
+            return Status::Failure;
+        }
+    };
+
+    let status = scaffold::handle(day, false);
+    if !status.is_success() {
+        return status;
+    }
+
+    let status = download::handle(day);
+    if !status.is_success() {
+        return status;
+    }
+
+    read::handle(day)
+}
+
+/// Prints a live countdown until `unlock`, updating it once per second.
+fn countdown(day: Day, unlock: SystemTime) {
+    let mut stdout = stdout();
+
+    while let Ok(remaining) = unlock.duration_since(SystemTime::now()) {
+        let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+        print!(
+            "\r⏳ Day {day} unlocks in {}",
+            format_hms(Duration::from_secs(secs))
+        );
+        let _ = stdout.flush();
+        thread::sleep(remaining.min(Duration::from_secs(1)));
+    }
+
+    println!("\r🎄 Day {day} is unlocked!                 ");
+}
@@ -0,0 +1,693 @@
+/// Project-wide defaults, read from `aoc.toml` in the repository root.
+/// Values can be overridden with the `AOC_CONFIG`, `AOC_YEAR` and `AOC_DATA_DIR` environment variables,
+/// which the global `--config`, `--year` and `--data-dir` flags set for all child processes.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+    sync::OnceLock,
+    time::SystemTime,
+};
+
+use crate::template::event::{Date, Event};
+use crate::template::readme::Section;
+use crate::template::readme_benchmarks::TableOptions;
+use crate::template::toml::{self, Entry, Value};
+use crate::template::Day;
+
+static CONFIG_FILE_PATH: &str = "aoc.toml";
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// First year advent of code took place.
+const FIRST_YEAR: i64 = 2015;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// Event year passed to aoc-cli. When unset, aoc-cli picks the current event.
+    pub year: Option<u16>,
+    /// Root folder for inputs, examples, puzzles and timings.
+    pub data_dir: PathBuf,
+    /// Whether `solve` and `all` use an optimized build by default.
+    pub release: bool,
+    /// Whether `solve` downloads a missing input without passing `--fetch`.
+    pub auto_fetch: bool,
+    /// Seconds after which a running solution is killed. `None` disables the timeout.
+    pub timeout_secs: Option<u64>,
+    pub bench: BenchConfig,
+    pub readme: ReadmeConfig,
+    pub scaffold: ScaffoldConfig,
+    pub submit: SubmitConfig,
+    pub leaderboard: LeaderboardConfig,
+    /// Calendars overriding [`Event::builtin`], set with `[events.YYYY]` tables.
+    pub events: Vec<Event>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchConfig {
+    /// Approximate wall time a benchmark should take, in milliseconds.
+    pub target_ms: u64,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReadmeConfig {
+    pub path: PathBuf,
+    /// Generated sections with the HTML comment delimiting them, set in the `[readme.sections]` table.
+    pub sections: Vec<(Section, String)>,
+    pub benchmarks: TableOptions,
+}
+
+impl ReadmeConfig {
+    /// Marker of a generated section, `None` if the section is not configured.
+    pub fn marker(&self, section: Section) -> Option<&str> {
+        self.sections
+            .iter()
+            .find(|(s, _)| *s == section)
+            .map(|(_, marker)| marker.as_str())
+    }
+
+    fn set_marker(&mut self, section: Section, marker: String) {
+        match self.sections.iter_mut().find(|(s, _)| *s == section) {
+            Some(entry) => entry.1 = marker,
+            None => self.sections.push((section, marker)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScaffoldConfig {
+    /// Module template used by `scaffold`. Falls back to the bundled `src/template.txt`.
+    pub template: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubmitConfig {
+    pub policy: SubmitPolicy,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeaderboardConfig {
+    /// Id of the private leaderboard fetched by `leaderboard`.
+    pub id: Option<u64>,
+}
+
+/// Controls whether `--submit` may send answers to the server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubmitPolicy {
+    /// Submit without asking.
+    Allow,
+    /// Ask for confirmation on the terminal before submitting.
+    Confirm,
+    /// Never submit.
+    Deny,
+}
+
+impl FromStr for SubmitPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(Self::Allow),
+            "confirm" => Ok(Self::Confirm),
+            "deny" => Ok(Self::Deny),
+            x => Err(format!(
+                "unknown submit policy `{x}`, expecting one of `allow`, `confirm` or `deny`."
+            )),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: None,
+            data_dir: PathBuf::from("data"),
+            release: false,
+            auto_fetch: false,
+            timeout_secs: None,
+            bench: BenchConfig {
+                target_ms: 1000,
+                min_samples: 10,
+                max_samples: 10000,
+            },
+            readme: ReadmeConfig {
+                path: PathBuf::from("README.md"),
+                sections: vec![(Section::Benchmarks, "<!--- benchmarking table --->".into())],
+                benchmarks: TableOptions::default(),
+            },
+            scaffold: ScaffoldConfig { template: None },
+            submit: SubmitConfig {
+                policy: SubmitPolicy::Allow,
+            },
+            leaderboard: LeaderboardConfig { id: None },
+            events: vec![],
+        }
+    }
+}
+
+impl Config {
+    /// Reads the config file (if present) and applies environment overrides.
+    pub fn load() -> Result<Self, ConfigError> {
+        let (path, explicit) = match env::var("AOC_CONFIG") {
+            Ok(path) if !path.is_empty() => (PathBuf::from(path), true),
+            _ => (PathBuf::from(CONFIG_FILE_PATH), false),
+        };
+
+        let mut config = match fs::read_to_string(&path) {
+            Ok(source) => Self::parse(&source, &path)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound && !explicit => Self::default(),
+            Err(e) => {
+                return Err(ConfigError::new(
+                    &path,
+                    None,
+                    format!("could not read file: {e}"),
+                ))
+            }
+        };
+
+        config.apply_env(&path)?;
+        config.validate(&path)?;
+        Ok(config)
+    }
+
+    /// Parses config file contents on top of the defaults.
+    pub fn parse(source: &str, path: &Path) -> Result<Self, ConfigError> {
+        let entries =
+            toml::parse(source).map_err(|e| ConfigError::new(path, Some(e.line), e.message))?;
+
+        let mut config = Self::default();
+
+        for entry in &entries {
+            config
+                .apply(entry)
+                .map_err(|message| ConfigError::new(path, Some(entry.line), message))?;
+        }
+
+        config.validate(path)?;
+        Ok(config)
+    }
+
+    fn apply(&mut self, entry: &Entry) -> Result<(), String> {
+        match entry.path().as_str() {
+            "year" => {
+                let year = expect_integer(entry, FIRST_YEAR..=i64::from(u16::MAX))?;
+                self.year = Some(year as u16);
+            }
+            "data_dir" => self.data_dir = expect_path(entry)?,
+            "release" => self.release = expect_bool(entry)?,
+            "auto_fetch" => self.auto_fetch = expect_bool(entry)?,
+            "timeout_secs" => {
+                self.timeout_secs = Some(expect_integer(entry, 1..=i64::from(u32::MAX))? as u64);
+            }
+            "bench.target_ms" => self.bench.target_ms = expect_integer(entry, 1..=60_000)? as u64,
+            "bench.min_samples" => {
+                self.bench.min_samples = expect_integer(entry, 1..=i64::MAX)? as u128;
+            }
+            "bench.max_samples" => {
+                self.bench.max_samples = expect_integer(entry, 1..=i64::MAX)? as u128;
+            }
+            "readme.path" => self.readme.path = expect_path(entry)?,
+            // shorthand for `readme.sections.benchmarks`, kept for existing configs.
+            "readme.marker" => self
+                .readme
+                .set_marker(Section::Benchmarks, expect_string(entry)?),
+            "readme.benchmarks.columns" => {
+                let Value::Array(values) = &entry.value else {
+                    return Err(type_error(entry, "an array of columns", &entry.value));
+                };
+                self.readme.benchmarks.columns = values
+                    .iter()
+                    .map(|value| match value {
+                        Value::String(column) => column.parse(),
+                        value => Err(type_error(entry, "an array of columns", value)),
+                    })
+                    .collect::<Result<_, _>>()?;
+            }
+            "readme.benchmarks.sort" => {
+                self.readme.benchmarks.sort = expect_string(entry)?.parse()?;
+            }
+            "readme.benchmarks.unit" => {
+                self.readme.benchmarks.unit = expect_string(entry)?.parse()?;
+            }
+            "readme.benchmarks.highlight" => self.readme.benchmarks.highlight = expect_bool(entry)?,
+            path if path.starts_with("readme.sections.") => {
+                let section = path.trim_start_matches("readme.sections.").parse()?;
+                self.readme.set_marker(section, expect_string(entry)?);
+            }
+            "scaffold.template" => self.scaffold.template = Some(expect_path(entry)?),
+            "submit.policy" => self.submit.policy = expect_string(entry)?.parse()?,
+            "leaderboard.id" => {
+                self.leaderboard.id = Some(expect_integer(entry, 1..=i64::MAX)? as u64);
+            }
+            path if path.starts_with("events.") => self.apply_event(entry)?,
+            key => return Err(format!("unknown key `{key}`.")),
+        }
+        Ok(())
+    }
+
+    /// Applies the keys of an `[events.YYYY]` table.
+    /// `days` is applied relative to `start`, so it has to come after it.
+    fn apply_event(&mut self, entry: &Entry) -> Result<(), String> {
+        let year = entry
+            .table
+            .strip_prefix("events.")
+            .and_then(|year| year.parse::<u16>().ok())
+            .filter(|year| i64::from(*year) >= FIRST_YEAR)
+            .ok_or_else(|| {
+                format!(
+                    "invalid table `[{}]`, expecting `[events.YYYY]` with a year of {FIRST_YEAR} or later.",
+                    entry.table
+                )
+            })?;
+
+        let index = match self.events.iter().position(|e| e.year == year) {
+            Some(index) => index,
+            None => {
+                self.events.push(Event::builtin(year));
+                self.events.len() - 1
+            }
+        };
+
+        let event = &mut self.events[index];
+        match entry.key.as_str() {
+            "start" => event.start = expect_string(entry)?.parse()?,
+            "end" => event.end = expect_string(entry)?.parse()?,
+            "days" => {
+                let days = expect_integer(entry, 1..=25)?;
+                event.end = Date::from_days_since_epoch(event.start.days_since_epoch() + days - 1);
+            }
+            "single_part" => {
+                let Value::Array(values) = &entry.value else {
+                    return Err(type_error(entry, "an array of days", &entry.value));
+                };
+                let days = values
+                    .iter()
+                    .map(|value| match value {
+                        Value::Integer(day @ 1..=25) => Ok(*day as u8),
+                        _ => Err(format!(
+                            "`{}` must only contain days between 1 and 25.",
+                            entry.path()
+                        )),
+                    })
+                    .collect::<Result<_, _>>()?;
+                event.single_part = Some(days);
+            }
+            _ => return Err(format!("unknown key `{}`.", entry.path())),
+        }
+        Ok(())
+    }
+
+    fn apply_env(&mut self, path: &Path) -> Result<(), ConfigError> {
+        if let Some(year) = non_empty_var("AOC_YEAR") {
+            let year = year
+                .parse::<u16>()
+                .ok()
+                .filter(|year| i64::from(*year) >= FIRST_YEAR)
+                .ok_or_else(|| {
+                    ConfigError::new(
+                        path,
+                        None,
+                        format!(
+                            "invalid year `{year}` set by `--year` or `AOC_YEAR`, expecting {FIRST_YEAR} or later."
+                        ),
+                    )
+                })?;
+            self.year = Some(year);
+        }
+
+        if let Some(data_dir) = non_empty_var("AOC_DATA_DIR") {
+            self.data_dir = PathBuf::from(data_dir);
+        }
+
+        Ok(())
+    }
+
+    fn validate(&self, path: &Path) -> Result<(), ConfigError> {
+        let err = |message: String| Err(ConfigError::new(path, None, message));
+
+        if self.bench.min_samples > self.bench.max_samples {
+            return err(format!(
+                "`bench.min_samples` ({}) must not be larger than `bench.max_samples` ({}).",
+                self.bench.min_samples, self.bench.max_samples
+            ));
+        }
+
+        for (section, marker) in &self.readme.sections {
+            if !(marker.starts_with("<!--") && marker.ends_with("-->")) {
+                return err(format!(
+                    "marker of the readme section `{}` must be an HTML comment like `<!--- benchmarking table --->`, found `{marker}`.",
+                    section.name()
+                ));
+            }
+            if self
+                .readme
+                .sections
+                .iter()
+                .filter(|(_, m)| m == marker)
+                .count()
+                > 1
+            {
+                return err(format!(
+                    "readme sections must use different markers, `{marker}` is used more than once."
+                ));
+            }
+        }
+
+        for event in &self.events {
+            if event.start > event.end || event.len() > 25 {
+                return err(format!(
+                    "`[events.{}]` must span 1 to 25 days, found {} to {}.",
+                    event.year, event.start, event.end
+                ));
+            }
+        }
+
+        if let Some(template) = &self.scaffold.template {
+            if !template.is_file() {
+                return err(format!(
+                    "`scaffold.template` points to \"{}\", which is not a file.",
+                    template.display()
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Path of a file inside the data folder, e.g. `data/inputs/01.txt`.
+    pub fn day_path(&self, folder: &str, day: Day, extension: &str) -> PathBuf {
+        self.data_dir
+            .join(folder)
+            .join(format!("{day}.{extension}"))
+    }
+
+    pub fn timings_path(&self) -> PathBuf {
+        self.data_dir.join("timings.json")
+    }
+
+    /// Every stored benchmark, kept to show how timings changed over time.
+    pub fn history_path(&self) -> PathBuf {
+        self.data_dir.join("timings_history.json")
+    }
+
+    pub fn answers_path(&self) -> PathBuf {
+        self.data_dir.join("answers.json")
+    }
+
+    pub fn stats_path(&self) -> PathBuf {
+        self.data_dir.join("stats.json")
+    }
+
+    /// Default output of `report`.
+    pub fn report_path(&self) -> PathBuf {
+        self.data_dir.join("report.html")
+    }
+
+    /// Cached JSON of the private leaderboard `id` in the `year` event.
+    pub fn leaderboard_path(&self, year: u16, id: u64) -> PathBuf {
+        self.data_dir.join(format!("leaderboard-{year}-{id}.json"))
+    }
+
+    /// Calendar of the event in `year`.
+    pub fn event(&self, year: u16) -> Event {
+        self.events
+            .iter()
+            .find(|e| e.year == year)
+            .cloned()
+            .unwrap_or_else(|| Event::builtin(year))
+    }
+
+    /// Calendar of the configured year's event, or of the current year's if no year is set.
+    pub fn current_event(&self) -> Event {
+        let year = self.year.unwrap_or_else(|| {
+            u16::try_from(Date::on_server(SystemTime::now()).year).unwrap_or(u16::MAX)
+        });
+        self.event(year)
+    }
+}
+
+/// Returns the project config, loading it on first access.
+/// Exits the process with an error message if the config is invalid.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(|| {
+        Config::load().unwrap_or_else(|e| {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        })
+    })
+}
+
+/// Loads the project config, reporting errors to the caller instead of exiting.
+pub fn init() -> Result<&'static Config, ConfigError> {
+    if let Some(config) = CONFIG.get() {
+        return Ok(config);
+    }
+    let config = Config::load()?;
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+fn non_empty_var(key: &str) -> Option<String> {
+    env::var(key).ok().filter(|v| !v.is_empty())
+}
+
+fn expect_string(entry: &Entry) -> Result<String, String> {
+    match &entry.value {
+        Value::String(s) if !s.is_empty() => Ok(s.clone()),
+        Value::String(_) => Err(format!("`{}` must not be empty.", entry.path())),
+        value => Err(type_error(entry, "a string", value)),
+    }
+}
+
+fn expect_path(entry: &Entry) -> Result<PathBuf, String> {
+    expect_string(entry).map(PathBuf::from)
+}
+
+fn expect_bool(entry: &Entry) -> Result<bool, String> {
+    match &entry.value {
+        Value::Boolean(b) => Ok(*b),
+        value => Err(type_error(entry, "a boolean", value)),
+    }
+}
+
+fn expect_integer(entry: &Entry, range: std::ops::RangeInclusive<i64>) -> Result<i64, String> {
+    match &entry.value {
+        Value::Integer(i) if range.contains(i) => Ok(*i),
+        Value::Integer(i) => Err(format!(
+            "`{}` must be between {} and {}, found {i}.",
+            entry.path(),
+            range.start(),
+            range.end()
+        )),
+        value => Err(type_error(entry, "an integer", value)),
+    }
+}
+
+fn type_error(entry: &Entry, expected: &str, found: &Value) -> String {
+    format!(
+        "expected `{}` to be {expected}, found {}.",
+        entry.path(),
+        found.type_name()
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error in the config file, pointing at the offending line if possible.
+#[derive(Debug)]
+pub struct ConfigError {
+    pub path: PathBuf,
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl ConfigError {
+    fn new(path: &Path, line: Option<usize>, message: String) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            line,
+            message,
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{line}: {}", self.path.display(), self.message),
+            None => write!(f, "{}: {}", self.path.display(), self.message),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{Config, SubmitPolicy};
+    use crate::day;
+    use crate::template::readme::Section;
+    use crate::template::readme_benchmarks::{Column, SortOrder, Unit};
+
+    fn parse(source: &str) -> Result<Config, String> {
+        Config::parse(source, Path::new("aoc.toml")).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn uses_defaults_for_empty_file() {
+        assert_eq!(parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn parses_all_sections() {
+        let config = parse(
+            r#"
+            year = 2023
+            data_dir = "aoc-data"
+            release = true
+            auto_fetch = true
+            timeout_secs = 30
+
+            [bench]
+            target_ms = 250
+            min_samples = 5
+            max_samples = 500
+
+            [readme]
+            path = "docs/README.md"
+            marker = "<!--- timings --->"
+
+            [submit]
+            policy = "confirm"
+
+            [leaderboard]
+            id = 123456
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(2023));
+        assert_eq!(config.data_dir, PathBuf::from("aoc-data"));
+        assert!(config.release);
+        assert!(config.auto_fetch);
+        assert_eq!(config.timeout_secs, Some(30));
+        assert_eq!(config.bench.target_ms, 250);
+        assert_eq!(config.bench.min_samples, 5);
+        assert_eq!(config.bench.max_samples, 500);
+        assert_eq!(config.readme.path, PathBuf::from("docs/README.md"));
+        assert_eq!(
+            config.readme.marker(Section::Benchmarks),
+            Some("<!--- timings --->")
+        );
+        assert_eq!(config.readme.marker(Section::Stars), None);
+        assert_eq!(config.submit.policy, SubmitPolicy::Confirm);
+        assert_eq!(config.leaderboard.id, Some(123456));
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        let err = parse("[bench]\ntarget = 1").unwrap_err();
+        assert_eq!(err, "aoc.toml:2: unknown key `bench.target`.");
+    }
+
+    #[test]
+    fn rejects_wrong_types() {
+        let err = parse("year = \"2024\"").unwrap_err();
+        assert_eq!(
+            err,
+            "aoc.toml:1: expected `year` to be an integer, found a string."
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_range_values() {
+        let err = parse("year = 2014").unwrap_err();
+        assert!(err.starts_with("aoc.toml:1: `year` must be between 2015"));
+    }
+
+    #[test]
+    fn rejects_inverted_sample_bounds() {
+        let err = parse("[bench]\nmin_samples = 100\nmax_samples = 10").unwrap_err();
+        assert!(err.contains("must not be larger than"));
+    }
+
+    #[test]
+    fn rejects_unknown_submit_policy() {
+        let err = parse("[submit]\npolicy = \"sometimes\"").unwrap_err();
+        assert!(err.contains("unknown submit policy `sometimes`"));
+    }
+
+    #[test]
+    fn parses_event_calendars() {
+        let config = parse("[events.2023]\nend = \"2023-12-12\"").unwrap();
+        let event = config.event(2023);
+        assert_eq!(event.start.to_string(), "2023-12-01");
+        assert_eq!(event.len(), 12);
+        assert_eq!(config.event(2022).len(), 25);
+
+        let config = parse("[events.2023]\ndays = 10\nsingle_part = [5, 10]").unwrap();
+        let event = config.event(2023);
+        assert_eq!(event.end.to_string(), "2023-12-10");
+        assert!(event.is_single_part(day!(5)));
+        assert!(!event.is_single_part(day!(9)));
+
+        let err = parse("[events.2023]\nsingle_part = [26]").unwrap_err();
+        assert!(err.contains("must only contain days between 1 and 25"));
+
+        let err = parse("[events.2023]\nstart = \"2023-11-01\"").unwrap_err();
+        assert!(err.contains("must span 1 to 25 days"));
+
+        let err = parse("[events.next]\nstart = \"2023-12-01\"").unwrap_err();
+        assert!(err.starts_with("aoc.toml:2: invalid table `[events.next]`"));
+    }
+
+    #[test]
+    fn rejects_non_comment_marker() {
+        let err = parse("[readme]\nmarker = \"benchmarks\"").unwrap_err();
+        assert!(err.contains("must be an HTML comment"));
+    }
+
+    #[test]
+    fn parses_benchmark_table_options() {
+        let config = parse(
+            "[readme.benchmarks]\ncolumns = [\"title\", \"total\"]\nsort = \"slowest\"\nunit = \"us\"\nhighlight = true",
+        )
+        .unwrap();
+        let table = &config.readme.benchmarks;
+        assert_eq!(table.columns, vec![Column::Title, Column::Total]);
+        assert_eq!(table.sort, SortOrder::Slowest);
+        assert_eq!(table.unit, Unit::Micros);
+        assert!(table.highlight);
+
+        let err = parse("[readme.benchmarks]\ncolumns = [\"median\"]").unwrap_err();
+        assert!(err.contains("unknown column `median`"));
+    }
+
+    #[test]
+    fn parses_readme_sections() {
+        let config = parse(
+            "[readme.sections]\nstars = \"<!--- stars --->\"\nbenchmarks = \"<!--- timings --->\"",
+        )
+        .unwrap();
+        assert_eq!(
+            config.readme.sections,
+            vec![
+                (Section::Benchmarks, "<!--- timings --->".to_string()),
+                (Section::Stars, "<!--- stars --->".to_string()),
+            ]
+        );
+
+        let err = parse("[readme.sections]\ntitles = \"<!--- titles --->\"").unwrap_err();
+        assert!(err.contains("unknown readme section `titles`"));
+        let err =
+            parse("[readme.sections]\nstars = \"<!--- benchmarking table --->\"").unwrap_err();
+        assert!(err.contains("must use different markers"));
+    }
+}
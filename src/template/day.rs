@@ -2,13 +2,10 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
-#[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
-
-#[cfg(feature = "today")]
-const SERVER_UTC_OFFSET: i32 = -5;
+use crate::template::config;
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// Events can be shorter, see [`crate::template::event::Event`] for the days of a specific year.
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -45,15 +42,11 @@ impl Day {
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the puzzle that unlocked today on the puzzle server, `None` outside of the current event.
     pub fn today() -> Option<Self> {
-        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
-        } else {
-            None
-        }
+        config::get()
+            .current_event()
+            .day_at(std::time::SystemTime::now())
     }
 }
 
@@ -100,20 +93,30 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of the configured event, e.g. from the 1st to the 25th.
 pub fn all_days() -> AllDays {
-    AllDays::new()
+    config::get().current_event().days()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields days of advent from the 1st up to a last day.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
+    /// Yields every possible day from the 1st to the 25th.
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        Self::until(25)
+    }
+
+    /// Yields days from the 1st to `last`, capped at the 25th.
+    pub fn until(last: u8) -> Self {
+        Self {
+            current: 1,
+            last: last.min(25),
+        }
     }
 }
 
@@ -121,7 +124,7 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
         // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
@@ -154,7 +157,7 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, AllDays, Day};
 
     #[test]
     fn all_days_iterator() {
@@ -187,6 +190,12 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_until_iterator() {
+        assert_eq!(AllDays::until(12).last(), Some(Day(12)));
+        assert_eq!(AllDays::until(30).count(), 25);
+    }
 }
 
 /* -------------------------------------------------------------------------- */
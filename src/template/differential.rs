@@ -0,0 +1,218 @@
+/// Differential testing: runs a slow reference implementation and an optimized one on the same
+/// inputs and reports the first input where they disagree.
+///
+/// ```ignore
+/// Differential::new(DAY, &INPUT_OPTIONS)
+///     .example()
+///     .real_input()
+///     .generated(0..50, 8)
+///     .assert_agree(|input| naive(input), |input| optimized(input));
+/// ```
+use std::fmt::{Debug, Display, Write};
+use std::ops::Range;
+
+use crate::template::fuzz::{minimize, Rng};
+use crate::template::input::{self, Options};
+use crate::template::{config, Day};
+
+/// A labeled input both implementations run on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Case {
+    /// Where the input comes from, e.g. `example` or `generated (seed 3)`.
+    pub label: String,
+    pub input: String,
+}
+
+/// The first input on which the implementations disagree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Divergence<T> {
+    pub label: String,
+    /// The smallest input found on which the implementations still disagree.
+    pub input: String,
+    pub reference: T,
+    pub optimized: T,
+}
+
+impl<T: Debug> Display for Divergence<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut out = format!(
+            "reference and optimized implementation disagree on the {} input:\n  reference: {:?}\n  optimized: {:?}\nMinimized input:\n",
+            self.label, self.reference, self.optimized
+        );
+        for line in self.input.lines() {
+            let _ = writeln!(out, "  {line}");
+        }
+        f.write_str(&out)
+    }
+}
+
+/// Inputs of a day to compare two implementations on, see the module documentation.
+#[derive(Clone, Debug)]
+pub struct Differential {
+    day: Day,
+    options: Options,
+    pub cases: Vec<Case>,
+}
+
+impl Differential {
+    /// Starts without inputs. `options` are the day's `INPUT_OPTIONS`, used to load inputs and
+    /// to generate and minimize them.
+    pub fn new(day: Day, options: &Options) -> Self {
+        Self {
+            day,
+            options: *options,
+            cases: vec![],
+        }
+    }
+
+    /// Adds the example of the day.
+    ///
+    /// # Panics
+    /// If the example can't be read.
+    #[must_use]
+    pub fn example(self) -> Self {
+        let path = config::get().day_path("examples", self.day, "txt");
+        let example = input::load(&path, &self.options).unwrap_or_else(|e| panic!("{e}"));
+        self.input("example", example)
+    }
+
+    /// Adds the real input of the day. Skipped if it has not been downloaded, e.g. in CI.
+    ///
+    /// # Panics
+    /// If the input exists but can't be read.
+    #[must_use]
+    pub fn real_input(self) -> Self {
+        let path = config::get().day_path("inputs", self.day, "txt");
+        if !path.exists() {
+            return self;
+        }
+        let real = input::load(&path, &self.options).unwrap_or_else(|e| panic!("{e}"));
+        self.input("real", real)
+    }
+
+    /// Adds an input of `size` records from the day's generator for every seed.
+    ///
+    /// # Panics
+    /// If the day has no generator.
+    #[must_use]
+    pub fn generated(mut self, seeds: Range<u64>, size: usize) -> Self {
+        let generate = self
+            .options
+            .generator
+            .unwrap_or_else(|| panic!("day {} has no generator, see `fuzz::Generator`", self.day));
+        for seed in seeds {
+            let (generated, _) =
+                input::normalize(&generate(&mut Rng::new(seed), size), self.options.trim);
+            self.cases.push(Case {
+                label: format!("generated (seed {seed}, size {size})"),
+                input: generated,
+            });
+        }
+        self
+    }
+
+    /// Adds a custom input.
+    #[must_use]
+    pub fn input(mut self, label: &str, input: String) -> Self {
+        self.cases.push(Case {
+            label: label.to_string(),
+            input,
+        });
+        self
+    }
+
+    /// Runs both implementations on every input in order and returns how many inputs were
+    /// compared, or the first divergence.
+    pub fn check<T: PartialEq>(
+        &self,
+        reference: impl Fn(&str) -> T,
+        optimized: impl Fn(&str) -> T,
+    ) -> Result<usize, Divergence<T>> {
+        for case in &self.cases {
+            if reference(&case.input) == optimized(&case.input) {
+                continue;
+            }
+
+            let input = minimize(&case.input, self.options.shape, |candidate| {
+                reference(candidate) != optimized(candidate)
+            });
+            return Err(Divergence {
+                label: case.label.clone(),
+                reference: reference(&input),
+                optimized: optimized(&input),
+                input,
+            });
+        }
+
+        Ok(self.cases.len())
+    }
+
+    /// Like [`Differential::check`], for tests.
+    ///
+    /// # Panics
+    /// On the first divergence, or if there are no inputs.
+    pub fn assert_agree<T: PartialEq + Debug>(
+        &self,
+        reference: impl Fn(&str) -> T,
+        optimized: impl Fn(&str) -> T,
+    ) -> usize {
+        assert!(!self.cases.is_empty(), "no inputs to compare");
+        match self.check(reference, optimized) {
+            Ok(compared) => compared,
+            Err(divergence) => panic!("{divergence}"),
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Differential;
+    use crate::template::input::Options;
+    use crate::template::{Day, Shape};
+
+    const OPTIONS: Options = Options {
+        shape: Shape::Lines,
+        ..Options::DEFAULT
+    };
+
+    fn sum(input: &str) -> u64 {
+        input.lines().map(|l| l.parse::<u64>().unwrap()).sum()
+    }
+
+    #[test]
+    fn agrees_on_all_inputs() {
+        let differential = Differential::new(Day::new(1).unwrap(), &OPTIONS)
+            .input("small", "1\n2\n".into())
+            .input("large", "10\n20\n30\n".into());
+        assert_eq!(
+            differential.check(sum, |input| input
+                .lines()
+                .fold(0, |acc, l| acc + l.parse::<u64>().unwrap())),
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn reports_the_first_minimized_divergence() {
+        // overflows past 255, like an optimization with a too small integer type.
+        let narrow = |input: &str| {
+            u64::from(
+                input
+                    .lines()
+                    .fold(0u8, |acc, l| acc.wrapping_add(l.parse::<u8>().unwrap())),
+            )
+        };
+        let divergence = Differential::new(Day::new(1).unwrap(), &OPTIONS)
+            .input("small", "1\n2\n".into())
+            .input("large", "1\n200\n2\n100\n3\n".into())
+            .check(sum, narrow)
+            .unwrap_err();
+
+        assert_eq!(divergence.label, "large");
+        assert_eq!(divergence.input, "200\n100\n");
+        assert_eq!((divergence.reference, divergence.optimized), (300, 44));
+        assert!(divergence
+            .to_string()
+            .starts_with("reference and optimized implementation disagree on the large input:"));
+    }
+}
@@ -0,0 +1,279 @@
+/// Event calendar: which dates an advent of code event spans and when its puzzles unlock.
+/// Puzzles unlock at midnight in the server's timezone (UTC-5), one per day of the event.
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::template::{AllDays, Day};
+
+/// Offset of the puzzle server's timezone to UTC, in hours.
+pub const SERVER_UTC_OFFSET: i64 = -5;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// A calendar date, written as `YYYY-MM-DD`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            _ => return None,
+        };
+        (1..=days_in_month)
+            .contains(&day)
+            .then_some(Self { year, month, day })
+    }
+
+    /// Days since 1970-01-01, see <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+    pub fn days_since_epoch(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Inverse of [`Date::days_since_epoch`].
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+
+    /// The date on the puzzle server at `time`.
+    pub fn on_server(time: SystemTime) -> Self {
+        Self::from_days_since_epoch(server_seconds(time).div_euclid(SECONDS_PER_DAY))
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || format!("invalid date `{s}`, expecting `YYYY-MM-DD`.");
+        let mut parts = s.split('-');
+        let (Some(year), Some(month), Some(day), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(err());
+        };
+        Date::new(
+            year.parse().map_err(|_| err())?,
+            month.parse().map_err(|_| err())?,
+            day.parse().map_err(|_| err())?,
+        )
+        .ok_or_else(err)
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// The date range of an event. Day 1 unlocks on `start`, the last day on `end`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+    pub year: u16,
+    pub start: Date,
+    pub end: Date,
+    /// Days without a second part. `None` means only the last day of the event.
+    pub single_part: Option<Vec<u8>>,
+}
+
+impl Event {
+    /// Default calendar: December 1st to 25th, shortened to the 12th from 2025 on.
+    pub fn builtin(year: u16) -> Self {
+        let last = if year >= 2025 { 12 } else { 25 };
+        Self {
+            year,
+            start: Date::new(i32::from(year), 12, 1).unwrap(),
+            end: Date::new(i32::from(year), 12, last).unwrap(),
+            single_part: None,
+        }
+    }
+
+    /// Every day of this event, in order.
+    pub fn days(&self) -> AllDays {
+        AllDays::until(self.len())
+    }
+
+    pub fn contains(&self, day: Day) -> bool {
+        day.into_inner() <= self.len()
+    }
+
+    /// Whether `day` only has a first part, like the last day of an event.
+    pub fn is_single_part(&self, day: Day) -> bool {
+        match &self.single_part {
+            Some(days) => days.contains(&day.into_inner()),
+            None => day.into_inner() == self.len(),
+        }
+    }
+
+    /// Number of parts to solve on `day`.
+    pub fn parts(&self, day: Day) -> u8 {
+        if self.is_single_part(day) {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Number of puzzles in this event.
+    pub fn len(&self) -> u8 {
+        let days = self.end.days_since_epoch() - self.start.days_since_epoch() + 1;
+        u8::try_from(days.max(0)).unwrap_or(u8::MAX)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// When the puzzle for `day` unlocks, `None` if the event has no such day.
+    pub fn unlock_time(&self, day: Day) -> Option<SystemTime> {
+        if day.into_inner() > self.len() {
+            return None;
+        }
+        let days = self.start.days_since_epoch() + i64::from(day.into_inner()) - 1;
+        let seconds = days * SECONDS_PER_DAY - SERVER_UTC_OFFSET * 3600;
+        Some(UNIX_EPOCH + Duration::from_secs(u64::try_from(seconds).ok()?))
+    }
+
+    /// The puzzle that unlocked on the current server date, `None` outside of the event.
+    pub fn day_at(&self, time: SystemTime) -> Option<Day> {
+        let offset = Date::on_server(time).days_since_epoch() - self.start.days_since_epoch();
+        u8::try_from(offset + 1)
+            .ok()
+            .filter(|day| *day <= self.len())
+            .and_then(Day::new)
+    }
+
+    /// The next puzzle to unlock after `time`, `None` once the event is over.
+    pub fn next_unlock(&self, time: SystemTime) -> Option<(Day, SystemTime)> {
+        (1..=self.len())
+            .filter_map(Day::new)
+            .filter_map(|day| self.unlock_time(day).map(|unlock| (day, unlock)))
+            .find(|(_, unlock)| *unlock > time)
+    }
+}
+
+/// Formats a duration as `hh:mm:ss`, prefixed with the number of days if longer than a day.
+pub fn format_hms(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (days, hours, minutes, seconds) =
+        (secs / 86_400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
+/// Formats a unix timestamp in the puzzle server's timezone, e.g. `2024-12-01 00:04`.
+pub fn format_server_time(timestamp: u64) -> String {
+    let seconds = i64::try_from(timestamp).unwrap_or(i64::MAX) + SERVER_UTC_OFFSET * 3600;
+    let date = Date::from_days_since_epoch(seconds.div_euclid(SECONDS_PER_DAY));
+    let time = seconds.rem_euclid(SECONDS_PER_DAY);
+    format!("{date} {:02}:{:02}", time / 3600, time / 60 % 60)
+}
+
+fn server_seconds(time: SystemTime) -> i64 {
+    let seconds = match time.duration_since(UNIX_EPOCH) {
+        Ok(d) => i64::try_from(d.as_secs()).unwrap_or(i64::MAX),
+        Err(e) => -i64::try_from(e.duration().as_secs()).unwrap_or(i64::MAX),
+    };
+    seconds + SERVER_UTC_OFFSET * 3600
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{Date, Event};
+    use crate::day;
+
+    #[test]
+    fn converts_dates() {
+        let date = Date::new(2024, 12, 1).unwrap();
+        assert_eq!(date.days_since_epoch(), 20058);
+        assert_eq!(Date::from_days_since_epoch(20058), date);
+        assert_eq!(
+            Date::from_days_since_epoch(0),
+            Date::new(1970, 1, 1).unwrap()
+        );
+        assert_eq!(
+            "2024-02-29".parse::<Date>(),
+            Ok(Date::new(2024, 2, 29).unwrap())
+        );
+        assert!("2023-02-29".parse::<Date>().is_err());
+        assert!("2024-12".parse::<Date>().is_err());
+    }
+
+    #[test]
+    fn computes_unlock_times() {
+        let event = Event::builtin(2024);
+        assert_eq!(event.len(), 25);
+        // 2024-12-01T05:00:00Z
+        let unlock = UNIX_EPOCH + Duration::from_secs(1_733_029_200);
+        assert_eq!(event.unlock_time(day!(1)), Some(unlock));
+        assert_eq!(
+            event.unlock_time(day!(25)),
+            Some(unlock + Duration::from_secs(24 * 86_400))
+        );
+
+        assert_eq!(event.day_at(unlock - Duration::from_secs(1)), None);
+        assert_eq!(event.day_at(unlock), Some(day!(1)));
+        assert_eq!(
+            event.next_unlock(unlock - Duration::from_secs(1)),
+            Some((day!(1), unlock))
+        );
+        assert_eq!(event.next_unlock(unlock).map(|(day, _)| day), Some(day!(2)));
+        assert_eq!(
+            event.next_unlock(unlock + Duration::from_secs(25 * 86_400)),
+            None
+        );
+    }
+
+    #[test]
+    fn shortens_recent_events() {
+        let event = Event::builtin(2025);
+        assert_eq!(event.len(), 12);
+        assert_eq!(event.days().last(), Some(day!(12)));
+        assert_eq!(event.unlock_time(day!(13)), None);
+        assert!(!event.contains(day!(13)));
+    }
+
+    #[test]
+    fn detects_single_part_days() {
+        let mut event = Event::builtin(2024);
+        assert!(event.is_single_part(day!(25)));
+        assert!(!event.is_single_part(day!(24)));
+        assert_eq!(event.parts(day!(24)), 2);
+
+        event.single_part = Some(vec![]);
+        assert!(!event.is_single_part(day!(25)));
+    }
+}
@@ -0,0 +1,382 @@
+/// Random puzzle inputs for stress tests, see `cargo fuzz-day`.
+/// A day implements [`Generator`] and passes it to `solution!`, e.g.
+/// `solution!(15, generator = Some(Warehouse::generate))`. The solution then runs both parts on
+/// inputs generated from consecutive seeds and reports every panic with a minimized input.
+use std::cell::RefCell;
+use std::fmt::Write as _;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+
+use crate::template::input::{self, Options, Shape};
+use crate::template::{config, Day, Status, ANSI_BOLD, ANSI_RESET};
+
+/// Produces random, valid puzzle inputs.
+pub trait Generator {
+    /// An input of about `size` records, e.g. lines of a list or rows of a grid.
+    /// The same `rng` state must always produce the same input.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// [`Generator::generate`] of a day, as stored in [`Options::generator`].
+pub type GenerateFn = fn(&mut Rng, usize) -> String;
+
+/// A part number with a function that runs the part and discards its answer.
+pub type Part<'a> = (u8, &'a dyn Fn(&str));
+
+/// Small, seedable random number generator (`SplitMix64`). Not suitable for cryptography.
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`. `n` must not be zero.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "`Rng::below` needs a non-empty range");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number in `min..=max`.
+    pub fn between(&mut self, min: usize, max: usize) -> usize {
+        min + self.below(max - min + 1)
+    }
+
+    /// `true` with a probability of `numerator / denominator`.
+    pub fn chance(&mut self, numerator: usize, denominator: usize) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    /// A random element of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+/// Flags passed to a solution binary by `cargo fuzz-day`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FuzzOptions {
+    /// Seed of the first input, the next inputs use the following seeds.
+    pub seed: u64,
+    /// Number of inputs to run.
+    pub count: u64,
+    /// Size of each input in records, see [`Generator::generate`].
+    pub size: usize,
+    /// Store minimized failing inputs as regression examples, see [`regression_path`].
+    pub save: bool,
+}
+
+impl Default for FuzzOptions {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            count: 100,
+            size: 20,
+            save: false,
+        }
+    }
+}
+
+/// A panic caught while running a part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// `file:line:column` of the panic, used to tell apart bugs while minimizing.
+    pub location: String,
+}
+
+/// A part that panicked on a generated input.
+#[derive(Clone, Debug)]
+pub struct Failure {
+    pub seed: u64,
+    pub part: u8,
+    pub panic: Panic,
+    /// The smallest input found that panics at the same location.
+    pub input: String,
+}
+
+thread_local! {
+    static LAST_PANIC: RefCell<Option<Panic>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with a panic hook that records panics for [`catch`] instead of printing them.
+pub(crate) fn record_panics<T>(f: impl FnOnce() -> T) -> T {
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| (*s).to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".into());
+        let location = info
+            .location()
+            .map_or("unknown location".into(), ToString::to_string);
+        LAST_PANIC.with(|last| *last.borrow_mut() = Some(Panic { message, location }));
+    }));
+
+    let result = f();
+    panic::set_hook(previous_hook);
+    result
+}
+
+/// Runs `func` and returns the panic it raised, if any.
+/// Expects the hook installed by [`record_panics`], which records the panic instead of printing it.
+pub(crate) fn catch(func: &dyn Fn(&str), input: &str) -> Option<Panic> {
+    LAST_PANIC.with(|last| last.borrow_mut().take());
+    match panic::catch_unwind(AssertUnwindSafe(|| func(input))) {
+        Ok(()) => None,
+        Err(_) => Some(
+            LAST_PANIC
+                .with(|last| last.borrow_mut().take())
+                .unwrap_or(Panic {
+                    message: "unknown panic".into(),
+                    location: "unknown location".into(),
+                }),
+        ),
+    }
+}
+
+/// Splits `input` into the records [`minimize`] removes, the separator between them and the
+/// trailing newlines. Records are values of a single line with separators, characters of a single
+/// line without, blocks for [`Shape::Blocks`] and lines otherwise.
+fn records(input: &str, shape: Shape) -> (Vec<&str>, &'static str, &str) {
+    let content = input.trim_end_matches('\n');
+    let suffix = &input[content.len()..];
+
+    let separator = match shape {
+        Shape::SingleLine if content.contains(", ") => ", ",
+        Shape::SingleLine if content.contains(',') => ",",
+        Shape::SingleLine if content.contains(' ') => " ",
+        Shape::SingleLine => "",
+        Shape::Blocks => "\n\n",
+        Shape::Any | Shape::Lines | Shape::Grid | Shape::Sections(_) => "\n",
+    };
+
+    let records = if separator.is_empty() {
+        content
+            .char_indices()
+            .map(|(i, c)| &content[i..i + c.len_utf8()])
+            .collect()
+    } else {
+        content.split(separator).collect()
+    };
+
+    (records, separator, suffix)
+}
+
+/// Removes records from `input` while it keeps its shape and `fails` still holds,
+/// trying large chunks first and single records last.
+pub fn minimize(input: &str, shape: Shape, fails: impl Fn(&str) -> bool) -> String {
+    let (mut records, separator, suffix) = records(input, shape);
+    let join = |records: &[&str]| records.join(separator) + suffix;
+    let mut chunk = (records.len() / 2).max(1);
+
+    loop {
+        let mut removed = false;
+        let mut start = 0;
+
+        while start < records.len() && records.len() > 1 {
+            let end = (start + chunk).min(records.len());
+            let kept: Vec<&str> = records[..start]
+                .iter()
+                .chain(&records[end..])
+                .copied()
+                .collect();
+            let candidate = join(&kept);
+
+            if !candidate.trim().is_empty()
+                && input::check_shape(&candidate, shape).is_ok()
+                && fails(&candidate)
+            {
+                records = kept;
+                removed = true;
+            } else {
+                start += chunk;
+            }
+        }
+
+        if chunk > 1 {
+            chunk /= 2;
+        } else if !removed {
+            break;
+        }
+    }
+
+    join(&records)
+}
+
+/// Path of the regression example for a failing `part` of `day`, e.g. `data/regressions/15-1234-2.txt`.
+pub fn regression_path(day: Day, seed: u64, part: u8) -> PathBuf {
+    config::get()
+        .data_dir
+        .join("regressions")
+        .join(format!("{day}-{seed}-{part}.txt"))
+}
+
+fn save(day: Day, failure: &Failure) -> Result<PathBuf, std::io::Error> {
+    let path = regression_path(day, failure.seed, failure.part);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, &failure.input)?;
+    Ok(path)
+}
+
+/// Renders a failure with its minimized input and how to reproduce it.
+pub fn render_failure(day: Day, size: usize, failure: &Failure) -> String {
+    let records = failure.input.lines().count();
+    let mut out = format!(
+        "{ANSI_BOLD}Seed {}, part {}{ANSI_RESET} panicked at {}:\n  {}\n",
+        failure.seed, failure.part, failure.panic.location, failure.panic.message
+    );
+    let _ = writeln!(
+        out,
+        "Minimized input ({records} line{}):",
+        if records == 1 { "" } else { "s" }
+    );
+    for line in failure.input.lines() {
+        let _ = writeln!(out, "  {line}");
+    }
+    let _ = writeln!(
+        out,
+        "Reproduce with `cargo fuzz-day {day} --seed {} --count 1 --size {size}`.",
+        failure.seed
+    );
+    out
+}
+
+/// Runs every part on `options.count` generated inputs and reports the parts that panicked.
+pub fn run(day: Day, parts: &[Part], input_options: &Options, options: &FuzzOptions) -> Status {
+    let Some(generate) = input_options.generator else {
+        eprintln!(
+            "Day {day} has no input generator. Implement `Generator` and set it with `solution!({}, generator = Some(...))`.",
+            day.into_inner()
+        );
+        return Status::Failure;
+    };
+
+    let mut failures: Vec<Failure> = vec![];
+    let mut invalid = 0;
+    let last_seed = options.seed.wrapping_add(options.count.saturating_sub(1));
+
+    record_panics(|| {
+        for i in 0..options.count {
+            let seed = options.seed.wrapping_add(i);
+            let (generated, _) = input::normalize(
+                &generate(&mut Rng::new(seed), options.size),
+                input_options.trim,
+            );
+
+            if let Err((line, e)) = input::check_shape(&generated, input_options.shape) {
+                eprintln!("Seed {seed}: the generator produced an invalid input, line {line}: {e}");
+                invalid += 1;
+                continue;
+            }
+
+            for (part, func) in parts {
+                let Some(panic) = catch(*func, &generated) else {
+                    continue;
+                };
+                let minimized = minimize(&generated, input_options.shape, |candidate| {
+                    catch(*func, candidate).is_some_and(|p| p.location == panic.location)
+                });
+                failures.push(Failure {
+                    seed,
+                    part: *part,
+                    panic,
+                    input: minimized,
+                });
+            }
+        }
+    });
+
+    for failure in &failures {
+        println!("{}", render_failure(day, options.size, failure));
+        if options.save {
+            match save(day, failure) {
+                Ok(path) => println!("Saved as \"{}\".\n", path.display()),
+                Err(e) => eprintln!("Failed to save the failing input: {e}\n"),
+            }
+        }
+    }
+
+    println!(
+        "Ran {} input{} of size {} (seeds {}..={last_seed}): {} panic{}.",
+        options.count,
+        if options.count == 1 { "" } else { "s" },
+        options.size,
+        options.seed,
+        failures.len(),
+        if failures.len() == 1 { "" } else { "s" }
+    );
+
+    if !failures.is_empty() {
+        Status::RuntimeFailed
+    } else if invalid > 0 {
+        Status::Failure
+    } else {
+        Status::Success
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{minimize, Rng};
+    use crate::template::Shape;
+
+    #[test]
+    fn rng_is_deterministic() {
+        let a: Vec<u64> = {
+            let mut rng = Rng::new(42);
+            (0..4).map(|_| rng.next_u64()).collect()
+        };
+        let mut rng = Rng::new(42);
+        assert_eq!(a, (0..4).map(|_| rng.next_u64()).collect::<Vec<_>>());
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+
+        for _ in 0..100 {
+            assert!((3..=5).contains(&rng.between(3, 5)));
+        }
+    }
+
+    #[test]
+    fn minimizes_lines() {
+        let input = "1\n2\n3\n13\n4\n5\n";
+        let minimized = minimize(input, Shape::Lines, |candidate| candidate.contains("13"));
+        assert_eq!(minimized, "13\n");
+    }
+
+    #[test]
+    fn minimizes_single_lines() {
+        let minimized = minimize("4, 8, 15, 16, 23, 42\n", Shape::SingleLine, |candidate| {
+            candidate.contains("15") && candidate.contains("42")
+        });
+        assert_eq!(minimized, "15, 42\n");
+
+        let minimized = minimize("0123456789\n", Shape::SingleLine, |candidate| {
+            candidate.contains('7')
+        });
+        assert_eq!(minimized, "7\n");
+    }
+
+    #[test]
+    fn keeps_the_shape() {
+        let input = "ab\ncd\n\n1\n2\n";
+        let minimized = minimize(input, Shape::Sections(2), |candidate| {
+            candidate.contains('2')
+        });
+        assert_eq!(minimized, "cd\n\n2\n");
+    }
+}
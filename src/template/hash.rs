@@ -0,0 +1,99 @@
+/// Hash maps and sets used by the solutions.
+/// With the `fast-hash` feature they hash with [`FxHasher`] instead of the standard library's
+/// `SipHash`, which resists collision attacks but is slow for small keys like integers and tuples.
+/// Construct them with `HashMap::default()`, as `HashMap::new()` only exists for the default hasher.
+use std::hash::Hasher;
+
+#[cfg(feature = "fast-hash")]
+pub type BuildHasher = std::hash::BuildHasherDefault<FxHasher>;
+
+#[cfg(not(feature = "fast-hash"))]
+pub type BuildHasher = std::collections::hash_map::RandomState;
+
+pub type HashMap<K, V> = std::collections::HashMap<K, V, BuildHasher>;
+pub type HashSet<T> = std::collections::HashSet<T, BuildHasher>;
+
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+/// The multiply-and-rotate hash of rustc (`FxHash`). Not suitable for untrusted keys.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FxHasher {
+    hash: u64,
+}
+
+impl FxHasher {
+    fn add(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            let mut word = [0; 8];
+            word.copy_from_slice(chunk);
+            self.add(u64::from_le_bytes(word));
+        }
+
+        let rest = chunks.remainder();
+        if !rest.is_empty() {
+            let mut word = [0; 8];
+            word[..rest.len()].copy_from_slice(rest);
+            self.add(u64::from_le_bytes(word));
+        }
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.add(u64::from(i));
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.add(u64::from(i));
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.add(u64::from(i));
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.add(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.add(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::hash::{BuildHasher, BuildHasherDefault, Hash};
+
+    use super::{FxHasher, HashMap};
+
+    fn hash<T: Hash>(value: &T) -> u64 {
+        BuildHasherDefault::<FxHasher>::default().hash_one(value)
+    }
+
+    #[test]
+    fn hashes_deterministically() {
+        assert_eq!(hash(&(3u32, 4u32)), hash(&(3u32, 4u32)));
+        assert_ne!(hash(&(3u32, 4u32)), hash(&(4u32, 3u32)));
+        assert_ne!(hash(&"abcdefghi"), hash(&"abcdefghj"));
+    }
+
+    #[test]
+    fn works_as_map_hasher() {
+        let mut map: HashMap<(usize, usize), u32> = HashMap::default();
+        for i in 0..100 {
+            map.insert((i, i * 2), 1);
+            *map.entry((i, i * 2)).or_default() += 1;
+        }
+        assert_eq!(map.len(), 100);
+        assert!(map.values().all(|&v| v == 2));
+    }
+}
@@ -0,0 +1,270 @@
+/// Normalization and shape checks for puzzle inputs.
+/// Files edited or checked out on Windows may contain a byte order mark or CRLF line endings,
+/// which silently break parsing code like `split("\n\n")` or `chars()`.
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+
+use crate::template::fuzz::GenerateFn;
+
+/// Per-day input handling, set through the `solution!` macro, e.g. `solution!(9, shape = Shape::SingleLine, trim = true)`.
+#[derive(Clone, Copy, Debug)]
+pub struct Options {
+    /// Shape the real input is checked against before running the solution.
+    pub shape: Shape,
+    /// Removes trailing newlines from the input.
+    pub trim: bool,
+    /// Produces random inputs for `cargo fuzz-day` and `cargo scale`, see [`Generator`](crate::template::fuzz::Generator).
+    /// Without it, `cargo scale` truncates the real input instead.
+    pub generator: Option<GenerateFn>,
+}
+
+impl Options {
+    pub const DEFAULT: Self = Self {
+        shape: Shape::Any,
+        trim: false,
+        generator: None,
+    };
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shape {
+    /// Accepts any input.
+    Any,
+    /// A single line, e.g. a disk map.
+    SingleLine,
+    /// One or more lines without blank lines in between.
+    Lines,
+    /// Lines of equal, non-zero width.
+    Grid,
+    /// Exactly this many non-empty sections, separated by a single blank line.
+    Sections(usize),
+    /// One or more non-empty blocks of lines, separated by a single blank line, e.g. claw machines.
+    Blocks,
+}
+
+/// A change made while normalizing an input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change {
+    ByteOrderMark,
+    LineEndings,
+    TrailingBlankLines,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Change::ByteOrderMark => "stripped a byte order mark",
+            Change::LineEndings => "converted CRLF line endings to LF",
+            Change::TrailingBlankLines => "removed trailing blank lines",
+        };
+        f.write_str(description)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct InputError {
+    pub path: String,
+    /// 1-based line the error refers to, if any.
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{}: {}", self.path, line, self.message),
+            None => write!(f, "{}: {}", self.path, self.message),
+        }
+    }
+}
+
+/// Strips a byte order mark and unifies line endings to `\n`.
+/// With `trim`, trailing newlines are removed as well.
+pub fn normalize(raw: &str, trim: bool) -> (String, Vec<Change>) {
+    let mut changes = vec![];
+
+    let text = match raw.strip_prefix('\u{feff}') {
+        Some(rest) => {
+            changes.push(Change::ByteOrderMark);
+            rest
+        }
+        None => raw,
+    };
+
+    let mut text = if text.contains('\r') {
+        changes.push(Change::LineEndings);
+        text.replace("\r\n", "\n").replace('\r', "\n")
+    } else {
+        text.to_string()
+    };
+
+    if trim {
+        let trimmed_len = text.trim_end_matches('\n').len();
+        // a single trailing newline is expected and dropped silently.
+        if text.len() - trimmed_len > 1 {
+            changes.push(Change::TrailingBlankLines);
+        }
+        text.truncate(trimmed_len);
+    }
+
+    (text, changes)
+}
+
+/// Checks `input` against `shape`, reporting the first offending line.
+pub fn check_shape(input: &str, shape: Shape) -> Result<(), (usize, String)> {
+    let lines: Vec<&str> = input
+        .strip_suffix('\n')
+        .unwrap_or(input)
+        .split('\n')
+        .collect();
+
+    let blank_line = |lines: &[&str]| lines.iter().position(|line| line.is_empty());
+
+    match shape {
+        Shape::Any => Ok(()),
+        Shape::SingleLine => match lines.len() {
+            1 if !lines[0].is_empty() => Ok(()),
+            1 => Err((1, "expected a single line, found an empty input.".into())),
+            n => Err((2, format!("expected a single line, found {n} lines."))),
+        },
+        Shape::Lines => match blank_line(&lines) {
+            Some(i) => Err((i + 1, "unexpected blank line.".into())),
+            None => Ok(()),
+        },
+        Shape::Grid => {
+            if let Some(i) = blank_line(&lines) {
+                return Err((i + 1, "unexpected blank line in grid.".into()));
+            }
+            let width = lines[0].chars().count();
+            match lines.iter().position(|line| line.chars().count() != width) {
+                Some(i) => Err((
+                    i + 1,
+                    format!(
+                        "expected a grid row of width {width}, found width {}.",
+                        lines[i].chars().count()
+                    ),
+                )),
+                None => Ok(()),
+            }
+        }
+        Shape::Sections(_) | Shape::Blocks => {
+            let expected = match shape {
+                Shape::Sections(expected) => expected,
+                _ => usize::MAX,
+            };
+            let mut sections = 0;
+            let mut in_section = false;
+            for (i, line) in lines.iter().enumerate() {
+                if line.is_empty() {
+                    if !in_section {
+                        return Err((i + 1, "unexpected blank line.".into()));
+                    }
+                    in_section = false;
+                } else if !in_section {
+                    sections += 1;
+                    if sections > expected {
+                        return Err((
+                            i + 1,
+                            format!("expected {expected} section(s), found more."),
+                        ));
+                    }
+                    in_section = true;
+                }
+            }
+            if !in_section {
+                return Err((lines.len(), "unexpected blank line.".into()));
+            }
+            if sections < expected && shape != Shape::Blocks {
+                return Err((
+                    lines.len(),
+                    format!("expected {expected} section(s) separated by blank lines, found {sections}."),
+                ));
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Reads and normalizes the file at `path`, printing a warning for every change made.
+pub fn load(path: &Path, options: &Options) -> Result<String, InputError> {
+    let err = |line: Option<usize>, message: String| InputError {
+        path: path.display().to_string(),
+        line,
+        message,
+    };
+
+    let raw = fs::read_to_string(path)
+        .map_err(|e| err(None, format!("could not open input file: {e}")))?;
+    let (text, changes) = normalize(&raw, options.trim);
+
+    for change in changes {
+        eprintln!("warning: {}: {change}.", path.display());
+    }
+
+    check_shape(&text, options.shape).map_err(|(line, message)| err(Some(line), message))?;
+
+    Ok(text)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check_shape, normalize, Change, Shape};
+
+    #[test]
+    fn normalizes_bom_and_line_endings() {
+        let (text, changes) = normalize("\u{feff}a\r\nb\r\n\r\nc\r\n", false);
+        assert_eq!(text, "a\nb\n\nc\n");
+        assert_eq!(changes, vec![Change::ByteOrderMark, Change::LineEndings]);
+    }
+
+    #[test]
+    fn leaves_clean_input_alone() {
+        let (text, changes) = normalize("a\nb\n", false);
+        assert_eq!(text, "a\nb\n");
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn trims_trailing_newlines() {
+        assert_eq!(normalize("2333\n", true), ("2333".into(), vec![]));
+        assert_eq!(
+            normalize("2333\n\n\n", true),
+            ("2333".into(), vec![Change::TrailingBlankLines])
+        );
+    }
+
+    #[test]
+    fn checks_single_line() {
+        assert!(check_shape("2333133121414131402\n", Shape::SingleLine).is_ok());
+        assert_eq!(check_shape("23\n33\n", Shape::SingleLine).unwrap_err().0, 2);
+        assert_eq!(check_shape("", Shape::SingleLine).unwrap_err().0, 1);
+    }
+
+    #[test]
+    fn checks_grid() {
+        assert!(check_shape("..#\n#..\n", Shape::Grid).is_ok());
+        assert_eq!(check_shape("..#\n#..\n.#\n", Shape::Grid).unwrap_err().0, 3);
+        assert_eq!(check_shape("..#\n\n#..", Shape::Grid).unwrap_err().0, 2);
+    }
+
+    #[test]
+    fn checks_sections() {
+        let input = "Register A: 729\n\nProgram: 0,1,5,4,3,0\n";
+        assert!(check_shape(input, Shape::Sections(2)).is_ok());
+        assert_eq!(check_shape(input, Shape::Sections(3)).unwrap_err().0, 3);
+        assert_eq!(check_shape(input, Shape::Sections(1)).unwrap_err().0, 3);
+        assert!(check_shape(input, Shape::Blocks).is_ok());
+        assert_eq!(
+            check_shape("a\n\n\nb", Shape::Sections(2)).unwrap_err().0,
+            3
+        );
+        assert_eq!(check_shape("a\n\n\nb", Shape::Blocks).unwrap_err().0, 3);
+    }
+}
@@ -0,0 +1,334 @@
+/// Private leaderboards, parsed from the JSON served at
+/// `https://adventofcode.com/<year>/leaderboard/private/view/<id>.json`.
+use std::{collections::HashMap, fmt::Write, str::FromStr, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::event::{format_hms, format_server_time, Event};
+use crate::template::Day;
+
+/// Star timestamps of a member for one day, as unix timestamps.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayCompletion {
+    pub day: Day,
+    pub first_star: u64,
+    pub second_star: Option<u64>,
+}
+
+impl DayCompletion {
+    /// Time between the first and the second star.
+    pub fn delta(&self) -> Option<Duration> {
+        self.second_star
+            .map(|ts| Duration::from_secs(ts.saturating_sub(self.first_star)))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// `None` for anonymous users.
+    pub name: Option<String>,
+    pub local_score: u64,
+    pub stars: u64,
+    pub last_star_ts: u64,
+    /// Completed days, in order.
+    pub days: Vec<DayCompletion>,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    pub fn completion(&self, day: Day) -> Option<&DayCompletion> {
+        self.days.iter().find(|c| c.day == day)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    /// Members ordered by local score, then stars, then whoever got their last star first.
+    pub fn standings(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.iter().collect();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.last_star_ts.cmp(&b.last_star_ts))
+                .then(a.id.cmp(&b.id))
+        });
+        members
+    }
+
+    /// Renders the standings with a star calendar: `*` for both stars, `+` for the first only.
+    pub fn render_standings(&self, event: &Event) -> String {
+        let calendar_width = usize::from(event.len());
+        let mut out = format!(
+            "{:>4}  {:>5}  {:>5}  {:<calendar_width$}  Name\n",
+            "Rank", "Score", "Stars", "Days"
+        );
+
+        for (rank, member) in self.standings().into_iter().enumerate() {
+            let calendar: String = event
+                .days()
+                .map(|day| match member.completion(day) {
+                    Some(c) if c.second_star.is_some() || event.is_single_part(day) => '*',
+                    Some(_) => '+',
+                    None => '.',
+                })
+                .collect();
+            let _ = writeln!(
+                out,
+                "{:>4}  {:>5}  {:>5}  {calendar}  {}",
+                format!("{})", rank + 1),
+                member.local_score,
+                member.stars,
+                member.display_name()
+            );
+        }
+
+        out
+    }
+
+    /// Renders star timestamps of all members who completed `day`, ordered by completion.
+    /// Returns `None` if nobody has a star for `day` yet.
+    pub fn render_day(&self, day: Day) -> Option<String> {
+        let mut completions: Vec<(&Member, &DayCompletion)> = self
+            .members
+            .iter()
+            .filter_map(|m| m.completion(day).map(|c| (m, c)))
+            .collect();
+
+        if completions.is_empty() {
+            return None;
+        }
+
+        completions.sort_by_key(|(m, c)| (c.second_star.unwrap_or(u64::MAX), c.first_star, m.id));
+
+        let mut out = format!(
+            "Day {day}\n  {:<16}  {:<16}  {:>11}  Name\n",
+            "1st star (UTC-5)", "2nd star", "Delta"
+        );
+        for (member, completion) in completions {
+            let _ = writeln!(
+                out,
+                "  {:<16}  {:<16}  {:>11}  {}",
+                format_server_time(completion.first_star),
+                completion
+                    .second_star
+                    .map_or("-".into(), format_server_time),
+                completion.delta().map_or("-".into(), format_hms),
+                member.display_name()
+            );
+        }
+
+        Some(out)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<String> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let event = json
+            .get("event")
+            .and_then(|v| v.get::<String>())
+            .ok_or("expected `json.event` to be a string.")?
+            .clone();
+
+        let owner_id = json
+            .get("owner_id")
+            .and_then(as_integer)
+            .ok_or("expected `json.owner_id` to be a number.")?;
+
+        let mut members = json
+            .get("members")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected `json.members` to be an object.")?
+            .values()
+            .map(Member::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        members.sort_by_key(|m| m.id);
+
+        Ok(Leaderboard {
+            event,
+            owner_id,
+            members,
+        })
+    }
+}
+
+impl TryFrom<&JsonValue> for Member {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected member to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(as_integer)
+                .ok_or_else(|| format!("Expected member to have a numeric `{key}`."))
+        };
+
+        let id = number("id")?;
+        let name = match json.get("name") {
+            Some(JsonValue::String(name)) => Some(name.clone()),
+            Some(JsonValue::Null) | None => None,
+            Some(_) => return Err(format!("Expected name of member {id} to be a string.")),
+        };
+
+        let mut days = json
+            .get("completion_day_level")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or_else(|| format!("Expected member {id} to have `completion_day_level`."))?
+            .iter()
+            .map(|(day, parts)| parse_completion(day, parts))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Member {id}: {e}"))?;
+        days.sort_by_key(|c| c.day);
+
+        Ok(Member {
+            id,
+            name,
+            local_score: number("local_score")?,
+            stars: number("stars")?,
+            last_star_ts: number("last_star_ts")?,
+            days,
+        })
+    }
+}
+
+fn parse_completion(day: &str, parts: &JsonValue) -> Result<DayCompletion, String> {
+    let parsed_day: Day = day
+        .parse()
+        .map_err(|_| format!("invalid day `{day}` in `completion_day_level`."))?;
+
+    let parts = parts
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or_else(|| format!("expected day {day} to be an object."))?;
+
+    let star = |part: &str| -> Result<Option<u64>, String> {
+        parts
+            .get(part)
+            .map(|star| {
+                star.get::<HashMap<String, JsonValue>>()
+                    .and_then(|star| star.get("get_star_ts"))
+                    .and_then(as_integer)
+                    .ok_or_else(|| format!("expected day {day} part {part} to have `get_star_ts`."))
+            })
+            .transpose()
+    };
+
+    Ok(DayCompletion {
+        day: parsed_day,
+        first_star: star("1")?
+            .ok_or_else(|| format!("expected day {day} to have a first star."))?,
+        second_star: star("2")?,
+    })
+}
+
+/// Reads a non-negative integer. The API sends some ids and timestamps as strings.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn as_integer(value: &JsonValue) -> Option<u64> {
+    match value {
+        JsonValue::Number(n) if *n >= 0.0 && n.fract() == 0.0 => Some(*n as u64),
+        JsonValue::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::Leaderboard;
+    use crate::day;
+    use crate::template::event::Event;
+
+    const FIXTURE: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/fixtures/leaderboard.json"
+    ));
+
+    fn fixture() -> Leaderboard {
+        Leaderboard::try_from(FIXTURE.to_string()).unwrap()
+    }
+
+    #[test]
+    fn parses_official_shape() {
+        let leaderboard = fixture();
+        assert_eq!(leaderboard.event, "2024");
+        assert_eq!(leaderboard.owner_id, 101);
+        assert_eq!(leaderboard.members.len(), 3);
+
+        let anonymous = &leaderboard.members[1];
+        assert_eq!(anonymous.name, None);
+        assert_eq!(anonymous.display_name(), "(anonymous user #202)");
+        assert_eq!(anonymous.days.len(), 2);
+        assert_eq!(anonymous.days[1].second_star, None);
+        assert_eq!(
+            anonymous.completion(day!(1)).unwrap().delta(),
+            Some(Duration::from_secs(1800))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_members() {
+        let err = Leaderboard::try_from(
+            r#"{"event":"2024","owner_id":1,"members":{"1":{"id":1,"name":"x","stars":1,"local_score":1,"last_star_ts":1,"completion_day_level":{"1":{"2":{"get_star_ts":5}}}}}}"#
+                .to_string(),
+        )
+        .unwrap_err();
+        assert_eq!(err, "Member 1: expected day 1 to have a first star.");
+        assert!(Leaderboard::try_from("[]".to_string()).is_err());
+    }
+
+    #[test]
+    fn renders_standings() {
+        let event = Event {
+            end: "2024-12-03".parse().unwrap(),
+            ..Event::builtin(2024)
+        };
+        let standings = fixture().render_standings(&event);
+        let lines: Vec<&str> = standings.lines().collect();
+        assert_eq!(lines[0], "Rank  Score  Stars  Days  Name");
+        assert_eq!(lines[1], "  1)     11      4  **.  alice");
+        assert_eq!(lines[2], "  2)      7      3  *+.  (anonymous user #202)");
+        assert_eq!(lines[3], "  3)      0      0  ...  carol");
+    }
+
+    #[test]
+    fn renders_star_timestamps_per_day() {
+        let leaderboard = fixture();
+        let rendered = leaderboard.render_day(day!(2)).unwrap();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], "Day 02");
+        assert_eq!(
+            lines[2],
+            "  2024-12-02 00:05  2024-12-02 00:20     00:15:00  alice"
+        );
+        assert_eq!(
+            lines[3],
+            "  2024-12-02 00:40  -                           -  (anonymous user #202)"
+        );
+        assert_eq!(leaderboard.render_day(day!(3)), None);
+    }
+}
@@ -0,0 +1,283 @@
+/// Performance experiments that benchmark every day in several builds and compare the totals to
+/// the first one: `cargo time --matrix` for each combination of the `fast-hash` and `bump-alloc`
+/// features, and `cargo time --profiles` for each [`BuildConfig`].
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::template::{Day, ANSI_BOLD, ANSI_RESET};
+
+/// Speedups within this factor of the first build count as noise.
+const NOISE: f64 = 1.05;
+
+/// A combination of the `fast-hash` and `bump-alloc` features.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Experiment {
+    pub fast_hash: bool,
+    pub bump_alloc: bool,
+}
+
+impl Experiment {
+    /// All combinations, starting with the default build the others are compared to.
+    pub const ALL: [Experiment; 4] = [
+        Experiment {
+            fast_hash: false,
+            bump_alloc: false,
+        },
+        Experiment {
+            fast_hash: true,
+            bump_alloc: false,
+        },
+        Experiment {
+            fast_hash: false,
+            bump_alloc: true,
+        },
+        Experiment {
+            fast_hash: true,
+            bump_alloc: true,
+        },
+    ];
+
+    pub fn name(self) -> &'static str {
+        match (self.fast_hash, self.bump_alloc) {
+            (false, false) => "default",
+            (true, false) => "fast-hash",
+            (false, true) => "bump-alloc",
+            (true, true) => "fast-hash + bump-alloc",
+        }
+    }
+
+    /// Arguments for `cargo build` that enable the features of this experiment in a release build.
+    pub fn cargo_args(self) -> &'static [&'static str] {
+        match (self.fast_hash, self.bump_alloc) {
+            (false, false) => &["--release"],
+            (true, false) => &["--release", "--features", "fast-hash"],
+            (false, true) => &["--release", "--features", "bump-alloc"],
+            (true, true) => &["--release", "--features", "fast-hash,bump-alloc"],
+        }
+    }
+}
+
+/// A named build configuration for `cargo time --profiles`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuildConfig {
+    Release,
+    /// Release build with fat LTO and a single codegen unit.
+    Lto,
+    /// Release build for the CPU of this machine, with `-C target-cpu=native`.
+    Native,
+    /// Release build with `opt-level = 2`.
+    OptLevel2,
+}
+
+impl BuildConfig {
+    /// All configurations, starting with the release build the others are compared to.
+    pub const ALL: [BuildConfig; 4] = [
+        BuildConfig::Release,
+        BuildConfig::Lto,
+        BuildConfig::Native,
+        BuildConfig::OptLevel2,
+    ];
+
+    /// Name the timings of this configuration are stored under.
+    pub fn name(self) -> &'static str {
+        match self {
+            BuildConfig::Release => "release",
+            BuildConfig::Lto => "lto",
+            BuildConfig::Native => "native",
+            BuildConfig::OptLevel2 => "opt-level-2",
+        }
+    }
+
+    /// Cargo profile of this configuration, see `Cargo.toml`.
+    /// Every configuration has its own profile, so they don't overwrite each other's builds.
+    pub fn cargo_profile(self) -> &'static str {
+        match self {
+            BuildConfig::Release => "release",
+            BuildConfig::Lto => "release-lto",
+            BuildConfig::Native => "release-native",
+            BuildConfig::OptLevel2 => "release-o2",
+        }
+    }
+
+    /// Arguments for `cargo build` that select the profile of this configuration.
+    pub fn cargo_args(self) -> &'static [&'static str] {
+        match self {
+            BuildConfig::Release => &["--release"],
+            BuildConfig::Lto => &["--profile", "release-lto"],
+            BuildConfig::Native => &["--profile", "release-native"],
+            BuildConfig::OptLevel2 => &["--profile", "release-o2"],
+        }
+    }
+
+    /// Flags added to `RUSTFLAGS`, as cargo profiles can't set the target CPU.
+    pub fn rustflags(self) -> Option<&'static str> {
+        match self {
+            BuildConfig::Native => Some("-C target-cpu=native"),
+            BuildConfig::Release | BuildConfig::Lto | BuildConfig::OptLevel2 => None,
+        }
+    }
+}
+
+/// Total time of a day in nanoseconds for each build, if it ran.
+pub type Row = (Day, Vec<Option<f64>>);
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos as u64))
+}
+
+/// Renders the times of every day with the speedup over the first of the builds named `names`,
+/// and a summary of how each build fares across the days that ran in every build.
+pub fn render(names: &[&str], rows: &[Row]) -> String {
+    let width = 24;
+    let builds = names.len();
+    let mut header = format!("{:<6}", "Day");
+    for name in names {
+        let _ = write!(header, "{name:<width$}");
+    }
+    let mut out = format!("{ANSI_BOLD}{}{ANSI_RESET}\n", header.trim_end());
+
+    let mut totals = vec![0.0; builds];
+    let mut faster = vec![0; builds];
+    let mut slower = vec![0; builds];
+    let mut compared = 0;
+
+    for (day, times) in rows {
+        let mut line = format!("{:<6}", day.to_string());
+        for (i, time) in times.iter().enumerate() {
+            let cell = match (time, times[0]) {
+                (Some(time), Some(base)) if i > 0 => {
+                    format!("{} ×{:.2}", format_nanos(*time), base / time)
+                }
+                (Some(time), _) => format_nanos(*time),
+                (None, _) => "✖".into(),
+            };
+            let _ = write!(line, "{cell:<width$}");
+        }
+        let _ = writeln!(out, "{}", line.trim_end());
+
+        let Some(times) = times.iter().copied().collect::<Option<Vec<f64>>>() else {
+            continue;
+        };
+        if times.len() != builds {
+            continue;
+        }
+        compared += 1;
+        for (i, time) in times.iter().enumerate() {
+            totals[i] += time;
+            if times[0] > time * NOISE {
+                faster[i] += 1;
+            } else if time > &(times[0] * NOISE) {
+                slower[i] += 1;
+            }
+        }
+    }
+
+    if compared == 0 {
+        let _ = writeln!(out, "\nNo day ran in every build.");
+        return out;
+    }
+
+    let _ = writeln!(
+        out,
+        "\n{ANSI_BOLD}Compared to {}{ANSI_RESET} ({compared} day{}):",
+        names[0],
+        if compared == 1 { "" } else { "s" }
+    );
+    for (i, name) in names.iter().enumerate().skip(1) {
+        let _ = writeln!(
+            out,
+            "  {name:<24}×{:.2} in total, faster on {}, slower on {}.",
+            totals[0] / totals[i],
+            faster[i],
+            slower[i]
+        );
+    }
+
+    let best = (0..builds)
+        .min_by(|a, b| totals[*a].total_cmp(&totals[*b]))
+        .unwrap_or(0);
+    if best == 0 || totals[0] <= totals[best] * NOISE {
+        let _ = writeln!(out, "No build is noticeably faster than {}.", names[0]);
+    } else {
+        let _ = writeln!(
+            out,
+            "Fastest overall: {ANSI_BOLD}{}{ANSI_RESET}.",
+            names[best]
+        );
+    }
+
+    out
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, BuildConfig, Experiment};
+    use crate::day;
+
+    const NAMES: [&str; 4] = [
+        "default",
+        "fast-hash",
+        "bump-alloc",
+        "fast-hash + bump-alloc",
+    ];
+
+    #[test]
+    fn names_every_combination() {
+        let names: Vec<&str> = Experiment::ALL.iter().map(|e| e.name()).collect();
+        assert_eq!(names, NAMES);
+        assert_eq!(
+            Experiment::ALL[3].cargo_args(),
+            ["--release", "--features", "fast-hash,bump-alloc"]
+        );
+    }
+
+    #[test]
+    fn gives_every_configuration_a_profile() {
+        for config in BuildConfig::ALL {
+            assert!(config.cargo_profile().starts_with("release"));
+            assert!(
+                config.cargo_args().len() == 1 || config.cargo_args()[1] == config.cargo_profile()
+            );
+        }
+        assert_eq!(
+            BuildConfig::Native.rustflags(),
+            Some("-C target-cpu=native")
+        );
+    }
+
+    #[test]
+    fn renders_speedups() {
+        let out = render(
+            &NAMES,
+            &[
+                (
+                    day!(1),
+                    vec![Some(2000.0), Some(1000.0), Some(2000.0), Some(900.0)],
+                ),
+                (
+                    day!(2),
+                    vec![Some(4000.0), Some(4000.0), Some(5000.0), None],
+                ),
+            ],
+        );
+        let lines: Vec<&str> = out.lines().collect();
+
+        assert!(lines[1].starts_with("01    2.0µs"));
+        assert!(lines[1].contains("1.0µs ×2.00"));
+        assert!(lines[2].contains('✖'));
+        assert!(out.contains("Compared to default\x1b[0m (1 day):"));
+        assert!(out.contains("  fast-hash               ×2.00 in total, faster on 1, slower on 0."));
+        assert!(out.contains("  bump-alloc              ×1.00 in total, faster on 0, slower on 0."));
+        assert!(out.contains("Fastest overall: \x1b[1mfast-hash + bump-alloc\x1b[0m."));
+    }
+
+    #[test]
+    fn reports_when_nothing_helps() {
+        let out = render(
+            &["release", "lto"],
+            &[(day!(1), vec![Some(1000.0), Some(990.0)])],
+        );
+        assert!(out.contains("No build is noticeably faster than release."));
+    }
+}
@@ -1,68 +1,229 @@
-use std::{env, fs};
-
+pub mod alloc;
+pub mod answers;
 pub mod aoc_cli;
+pub mod cli;
 pub mod commands;
+pub mod config;
+pub mod differential;
+pub mod event;
+pub mod fuzz;
+pub mod hash;
+pub mod input;
+pub mod leaderboard;
+pub mod matrix;
+pub mod ocr;
+pub mod readme;
+pub mod readme_benchmarks;
+pub mod report;
 pub mod runner;
+pub mod scale;
+pub mod snapshot;
+pub mod spans;
+pub mod stats;
+pub mod status;
+pub mod timings;
+pub mod viz;
 
 pub use day::*;
+pub use input::Shape;
+pub use status::Status;
 
 mod day;
-mod readme_benchmarks;
+mod readme_stars;
 mod run_multi;
-mod timings;
+mod toml;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
+/// The contents are normalized, see [`input::normalize`].
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    let filepath = config::get().day_path(folder, day, "txt");
+    input::load(&filepath, &input::Options::DEFAULT).unwrap_or_else(|e| panic!("{e}"))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
+    let filepath = config::get()
+        .data_dir
         .join(folder)
         .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    input::load(&filepath, &input::Options::DEFAULT).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Reads the regression examples saved by `cargo fuzz-day --save` for `day`, as pairs of file name
+/// and contents sorted by name. Run them in a test to make sure fixed panics stay fixed.
+#[must_use]
+pub fn read_regressions(day: Day) -> Vec<(String, String)> {
+    read_regressions_in(&config::get().data_dir.join("regressions"), day)
+}
+
+fn read_regressions_in(folder: &std::path::Path, day: Day) -> Vec<(String, String)> {
+    let prefix = format!("{day}-");
+    let Ok(entries) = std::fs::read_dir(folder) else {
+        return vec![];
+    };
+
+    let mut regressions: Vec<(String, String)> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            if !name.starts_with(&prefix) || !name.ends_with(".txt") {
+                return None;
+            }
+            let input = input::load(&entry.path(), &input::Options::DEFAULT)
+                .unwrap_or_else(|e| panic!("{e}"));
+            Some((name, input))
+        })
+        .collect();
+    regressions.sort();
+    regressions
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// `solution!(use day09)` runs the parts of the library module `solutions::day09`, which defines
+/// `DAY`, `INPUT_OPTIONS`, `part_one` and `part_two`. This is how the bins in `src/bin` are set up.
+/// A trailing `parse = <fn>`, e.g. `solution!(use day09, parse = day09::parse)`, lets `cargo time`
+/// measure the module's parsing on its own.
+///
+/// A standalone bin passes the day instead, e.g. `solution!(9)`, and defines the parts itself.
+/// Trailing `key = value` pairs then set the [`input::Options`] for the real input, e.g.
+/// `solution!(9, shape = Shape::SingleLine, trim = true)` to reject malformed inputs before solving.
+///
+/// In both forms, the optional parameter `1` or `2` after the day allows you to only run a single part.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    (use $module:ident, 1 $(, parse = $parse:expr)? $(,)?) => {
+        $crate::solution!(@use $module, [$($parse)?] [part_one, 1]);
+    };
+    (use $module:ident, 2 $(, parse = $parse:expr)? $(,)?) => {
+        $crate::solution!(@use $module, [$($parse)?] [part_two, 2]);
+    };
+    (use $module:ident $(, parse = $parse:expr)? $(,)?) => {
+        $crate::solution!(@use $module, [$($parse)?] [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, 1 $(, $key:ident = $value:expr)* $(,)?) => {
+        $crate::solution!(@impl $day, [part_one, 1], [$($key = $value),*]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($day:expr, 2 $(, $key:ident = $value:expr)* $(,)?) => {
+        $crate::solution!(@impl $day, [part_two, 2], [$($key = $value),*]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($day:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2], [$($key = $value),*]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@use $module:ident, [$($parse:expr)?] $( [$func:ident, $part:expr] )*) => {
+        #[allow(unused_imports)]
+        use $crate::solutions::$module;
+        use $crate::solutions::$module::{DAY, INPUT_OPTIONS, $($func),*};
+
+        $crate::solution!(@main [$($parse)?] $( [$func, $part] )*);
+    };
+
+    (@impl $day:expr, $( [$func:expr, $part:expr] )*, [$($key:ident = $value:expr),*]) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// How the real input is normalized and checked.
+        const INPUT_OPTIONS: $crate::template::input::Options = {
+            #[allow(unused_imports)]
+            use $crate::template::{fuzz::Generator, Shape};
+            #[allow(clippy::needless_update)]
+            $crate::template::input::Options {
+                $($key: $value,)*
+                ..$crate::template::input::Options::DEFAULT
+            }
+        };
+
+        $crate::solution!(@main [] $( [$func, $part] )*);
+    };
+
+    (@main [$($parse:expr)?] $( [$func:expr, $part:expr] )*) => {
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
-        fn main() {
+        #[cfg(feature = "bump-alloc")]
+        #[global_allocator]
+        static ALLOC: $crate::template::alloc::Bump = $crate::template::alloc::Bump::new();
+
+        fn main() -> std::process::ExitCode {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let options = match RunOptions::from_args() {
+                Ok(options) => options,
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    return $crate::template::Status::Usage.into();
+                }
+            };
+            if let Some(fuzz) = options.fuzz {
+                let parts: &[$crate::template::fuzz::Part] = &[$( ($part, &|input: &str| {
+                    let _ = $func(input);
+                }) ),*];
+                return $crate::template::fuzz::run(DAY, parts, &INPUT_OPTIONS, &fuzz).into();
+            }
+            let path = $crate::template::config::get().day_path("inputs", DAY, "txt");
+            let input = match $crate::template::input::load(&path, &INPUT_OPTIONS) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    return $crate::template::Status::Failure.into();
+                }
+            };
+            if let Some(scale) = options.scale {
+                $(
+                    if scale.part.is_none_or(|part| part == $part) {
+                        $crate::template::scale::run_part($func, &input, $part, &INPUT_OPTIONS, &scale);
+                    }
+                )*
+                return $crate::template::Status::Success.into();
+            }
+            $(
+                if options.time {
+                    time_parse($parse, &input);
+                }
+            )?
+            let answers = [$( ($part, run_part($func, &input, $part, &options)) ),*];
+            match options.submit {
+                Some(submit) => submit_answers(DAY, &answers, submit).into(),
+                None => $crate::template::Status::Success.into(),
+            }
         }
     };
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::read_regressions_in;
+    use crate::day;
+
+    #[test]
+    fn reads_regressions_of_a_day() {
+        let folder = std::env::temp_dir().join(format!("aoc-regressions-{}", std::process::id()));
+        std::fs::create_dir_all(&folder).unwrap();
+        for (name, contents) in [
+            ("20-2-2.txt", "b\n"),
+            ("20-1-1.txt", "a\r\n"),
+            ("2-1-1.txt", "other day\n"),
+            ("20-3-1.txt.bak", "backup\n"),
+        ] {
+            std::fs::write(folder.join(name), contents).unwrap();
+        }
+
+        let regressions = read_regressions_in(&folder, day!(20));
+        std::fs::remove_dir_all(&folder).unwrap();
+        assert_eq!(
+            regressions,
+            vec![
+                ("20-1-1.txt".to_string(), "a\n".to_string()),
+                ("20-2-2.txt".to_string(), "b\n".to_string()),
+            ]
+        );
+        assert!(read_regressions_in(&folder, day!(20)).is_empty());
+    }
+}
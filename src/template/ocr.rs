@@ -0,0 +1,320 @@
+/// Reads answers that a puzzle draws as block letters, so a day can return them as a `String`:
+///
+/// ```ignore
+/// let screen = render(&pixels); // e.g. "#..#.####.\n#..#.#....\n..."
+/// ocr::read(&screen).ok()
+/// ```
+///
+/// Knows the two fonts of the puzzles: letters 4 cells wide and 6 high, and 6 wide and 10 high.
+/// Lit cells are any character except `.` and space, e.g. `#` or `█`.
+use std::fmt::Display;
+
+/// A font whose letters start every `stride` columns.
+struct Font {
+    height: usize,
+    stride: usize,
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+const SMALL: Font = Font {
+    height: 6,
+    stride: 5,
+    glyphs: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        // the only letter that uses the column between letters.
+        ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+const LARGE: Font = Font {
+    height: 10,
+    stride: 8,
+    glyphs: &[
+        (
+            'A',
+            &[
+                "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'B',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+                "#....#", "#####.",
+            ],
+        ),
+        (
+            'C',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#....#", ".####.",
+            ],
+        ),
+        (
+            'E',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'F',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'G',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+                "#...##", ".###.#",
+            ],
+        ),
+        (
+            'H',
+            &[
+                "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'J',
+            &[
+                "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+                "#...#.", ".###..",
+            ],
+        ),
+        (
+            'K',
+            &[
+                "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+                "#...#.", "#....#",
+            ],
+        ),
+        (
+            'L',
+            &[
+                "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'N',
+            &[
+                "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+                "#...##", "#....#",
+            ],
+        ),
+        (
+            'P',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'R',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'X',
+            &[
+                "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'Z',
+            &[
+                "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+    ],
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OcrError {
+    /// No cell is lit.
+    Empty,
+    /// The lit cells span this many rows, but letters are 6 or 10 rows high.
+    Height(usize),
+    /// No letter matches the cells starting at this 0-based column, drawn with `#` and `.`.
+    UnknownGlyph { column: usize, glyph: String },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::Empty => f.write_str("no lit cells to read letters from."),
+            OcrError::Height(height) => write!(
+                f,
+                "the lit cells span {height} rows, but letters are 6 or 10 rows high."
+            ),
+            OcrError::UnknownGlyph { column, glyph } => {
+                write!(f, "unknown letter at column {column}:\n{glyph}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+/// Reads the letters drawn in `text`, see the module documentation.
+pub fn read(text: &str) -> Result<String, OcrError> {
+    let grid: Vec<Vec<bool>> = text
+        .lines()
+        .map(|line| line.chars().map(|ch| ch != '.' && ch != ' ').collect())
+        .collect();
+    read_grid(&grid)
+}
+
+/// Reads the letters drawn by the lit cells of `grid`, given row by row.
+/// Blank rows and columns around the letters are ignored.
+pub fn read_grid(grid: &[Vec<bool>]) -> Result<String, OcrError> {
+    let lit_rows: Vec<usize> = (0..grid.len())
+        .filter(|&y| grid[y].iter().any(|&lit| lit))
+        .collect();
+    let (Some(&top), Some(&bottom)) = (lit_rows.first(), lit_rows.last()) else {
+        return Err(OcrError::Empty);
+    };
+    let rows = &grid[top..=bottom];
+    let Some(font) = [SMALL, LARGE].into_iter().find(|f| f.height == rows.len()) else {
+        return Err(OcrError::Height(rows.len()));
+    };
+
+    let lit = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let blank_column = |x: usize| (0..rows.len()).all(|y| !lit(x, y));
+
+    let mut text = String::new();
+    let mut x = 0;
+    while x < width {
+        if blank_column(x) {
+            x += 1;
+            continue;
+        }
+
+        // a glyph like `I` starts with blank columns, so it starts before the first lit column.
+        let start = |glyph: &[&str]| {
+            let lead = glyph.iter().filter_map(|row| row.find('#')).min()?;
+            x.checked_sub(lead)
+        };
+        let matches = |glyph: &[&str], start: usize| {
+            glyph.iter().enumerate().all(|(y, row)| {
+                let row = row.as_bytes();
+                (0..font.stride).all(|dx| lit(start + dx, y) == (row.get(dx) == Some(&b'#')))
+            })
+        };
+        let found = font.glyphs.iter().find_map(|(letter, glyph)| {
+            let start = start(glyph)?;
+            matches(glyph, start).then_some((letter, start))
+        });
+        match found {
+            Some((letter, start)) => {
+                text.push(*letter);
+                x = start + font.stride;
+            }
+            None => {
+                let glyph = (0..rows.len())
+                    .map(|y| {
+                        (x..x + font.stride)
+                            .map(|x| if lit(x, y) { '#' } else { '.' })
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                return Err(OcrError::UnknownGlyph { column: x, glyph });
+            }
+        }
+    }
+
+    Ok(text)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{read, read_grid, Font, OcrError, LARGE, SMALL};
+
+    /// Draws every letter of `font` side by side, as a puzzle would.
+    fn draw_alphabet(font: &Font) -> (String, String) {
+        let letters = font.glyphs.iter().map(|(letter, _)| letter).collect();
+        let rows: Vec<String> = (0..font.height)
+            .map(|y| {
+                font.glyphs
+                    .iter()
+                    .map(|(_, glyph)| format!("{:.<width$}", glyph[y], width = font.stride))
+                    .collect()
+            })
+            .collect();
+        (letters, rows.join("\n"))
+    }
+
+    #[test]
+    fn reads_every_letter() {
+        for font in [SMALL, LARGE] {
+            assert!(font.glyphs.iter().all(|(_, g)| g.len() == font.height));
+            let (letters, drawing) = draw_alphabet(&font);
+            assert_eq!(read(&drawing), Ok(letters));
+        }
+    }
+
+    #[test]
+    fn reads_screens_with_padding() {
+        let screen = [
+            "",
+            "  █  █ ████ ███    ██ ",
+            "  █  █ █    █  █    █ ",
+            "  ████ ███  █  █    █ ",
+            "  █  █ █    ███     █ ",
+            "  █  █ █    █    █  █ ",
+            "  █  █ ████ █     ██  ",
+            "                      ",
+        ]
+        .join("\n");
+        assert_eq!(read(&screen), Ok("HEPJ".to_string()));
+
+        let grid: Vec<Vec<bool>> = [".##.", "#..#", "#..#", "####", "#..#", "#..#"]
+            .iter()
+            .map(|row| row.chars().map(|ch| ch == '#').collect())
+            .collect();
+        assert_eq!(read_grid(&grid), Ok("A".to_string()));
+    }
+
+    #[test]
+    fn reports_what_it_cannot_read() {
+        assert_eq!(read("....\n...."), Err(OcrError::Empty));
+        assert_eq!(read("#\n#\n#"), Err(OcrError::Height(3)));
+
+        let (_, drawing) = draw_alphabet(&SMALL);
+        let mut rows: Vec<String> = drawing.lines().map(String::from).collect();
+        // turn the B into something else.
+        rows[2].replace_range(5..9, "#..#");
+        let error = read(&rows.join("\n")).unwrap_err();
+        assert!(matches!(error, OcrError::UnknownGlyph { column: 5, .. }));
+        assert!(error
+            .to_string()
+            .starts_with("unknown letter at column 5:\n###.."));
+    }
+}
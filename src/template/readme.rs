@@ -0,0 +1,198 @@
+/// Keeps generated sections of the readme up to date.
+/// Every section is delimited by a pair of HTML comment markers, similar to how `aoc-readme-stars` works,
+/// and everything between the markers is replaced when the section is rendered.
+use std::{collections::HashMap, fmt::Display, fs, io, str::FromStr, time::SystemTime};
+
+use crate::template::answers::Ledger;
+use crate::template::readme_benchmarks::{self, Column, TableOptions};
+use crate::template::timings::Timings;
+use crate::template::{config, readme_stars, Day};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "{e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Kinds of sections that can be generated, configured in the `[readme.sections]` table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Section {
+    /// Benchmark table from `timings.json`, updated by `time --store`.
+    Benchmarks,
+    /// Stars per day from the answer ledger, with puzzle titles from the downloaded puzzles.
+    Stars,
+}
+
+impl Section {
+    pub const ALL: [Section; 2] = [Section::Benchmarks, Section::Stars];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Section::Benchmarks => "benchmarks",
+            Section::Stars => "stars",
+        }
+    }
+}
+
+impl FromStr for Section {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Section::ALL
+            .into_iter()
+            .find(|section| section.name() == s)
+            .ok_or_else(|| {
+                format!("unknown readme section `{s}`, expecting `benchmarks` or `stars`.")
+            })
+    }
+}
+
+struct SectionPosition {
+    pos_start: usize,
+    pos_end: usize,
+}
+
+fn locate_section(readme: &str, marker: &str) -> Result<SectionPosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
+
+    if matches.len() > 2 {
+        return Err(Error::Parser(format!(
+            "{marker}: too many occurences of marker in README."
+        )));
+    }
+
+    let pos_start = matches.first().map(|m| m.0).ok_or_else(|| {
+        Error::Parser(format!("{marker}: could not find section start position."))
+    })?;
+
+    let pos_end = matches
+        .last()
+        .map(|m| m.0 + m.1.len())
+        .ok_or_else(|| Error::Parser(format!("{marker}: could not find section end position.")))?;
+
+    Ok(SectionPosition { pos_start, pos_end })
+}
+
+/// Replaces the section delimited by `marker` with `lines`, wrapped in markers again.
+pub(crate) fn replace_section(s: &mut String, marker: &str, lines: &[String]) -> Result<(), Error> {
+    let positions = locate_section(s, marker)?;
+    let mut section = vec![marker.to_string()];
+    section.extend_from_slice(lines);
+    section.push(marker.to_string());
+    s.replace_range(positions.pos_start..positions.pos_end, &section.join("\n"));
+    Ok(())
+}
+
+/// Extracts the title from a puzzle downloaded by aoc-cli, e.g. `\--- Day 1: Historian Hysteria ---`.
+pub fn parse_title(puzzle: &str) -> Option<String> {
+    puzzle.lines().find_map(|line| {
+        let (_, rest) = line.split_once("--- Day ")?;
+        let (_, title) = rest.split_once(": ")?;
+        let title = title.trim_end().trim_end_matches("---").trim();
+        (!title.is_empty()).then(|| title.to_string())
+    })
+}
+
+/// Reads the titles of all downloaded puzzles among `days`.
+pub fn read_titles(days: &[Day]) -> HashMap<Day, String> {
+    let config = config::get();
+    days.iter()
+        .filter_map(|day| {
+            let puzzle = fs::read_to_string(config.day_path("puzzles", *day, "md")).ok()?;
+            parse_title(&puzzle).map(|title| (*day, title))
+        })
+        .collect()
+}
+
+/// Renders every configured section of one of the given kinds into the readme.
+/// Returns the sections that were updated.
+pub fn update(kinds: &[Section], table: &TableOptions) -> Result<Vec<Section>, Error> {
+    let config = config::get();
+    let path = &config.readme.path;
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    let event = config.current_event();
+    let mut updated = vec![];
+
+    for (section, marker) in &config.readme.sections {
+        if !kinds.contains(section) {
+            continue;
+        }
+
+        match section {
+            Section::Benchmarks => {
+                let timings = Timings::read_from_file();
+                let total_millis = timings.total_millis();
+                let titles = if table.columns.contains(&Column::Title) {
+                    read_titles(&timings.data.iter().map(|t| t.day).collect::<Vec<_>>())
+                } else {
+                    HashMap::new()
+                };
+                readme_benchmarks::update_content(
+                    &mut readme,
+                    marker,
+                    &event,
+                    timings,
+                    total_millis,
+                    table,
+                    &titles,
+                )?;
+            }
+            Section::Stars => {
+                let days: Vec<_> = event
+                    .days()
+                    .filter(|day| {
+                        event
+                            .unlock_time(*day)
+                            .is_some_and(|unlock| unlock <= SystemTime::now())
+                    })
+                    .collect();
+                let ledger = Ledger::read_from_file().map_err(Error::Parser)?;
+                let titles = read_titles(&days);
+                readme_stars::update_content(&mut readme, marker, &event, &days, &ledger, &titles)?;
+            }
+        }
+        updated.push(*section);
+    }
+
+    fs::write(path, &readme)?;
+    Ok(updated)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_title, replace_section};
+
+    #[test]
+    fn parses_puzzle_titles() {
+        let puzzle = "\\--- Day 1: Historian Hysteria ---\n----------\n\nThe *Chief Historian*...";
+        assert_eq!(parse_title(puzzle), Some("Historian Hysteria".into()));
+        assert_eq!(
+            parse_title("## --- Day 12: Garden Groups ---"),
+            Some("Garden Groups".into())
+        );
+        assert_eq!(parse_title("no title"), None);
+    }
+
+    #[test]
+    fn replaces_sections_between_markers() {
+        let mut s = "a\n<!-- x -->old<!-- x -->\nb".to_string();
+        replace_section(&mut s, "<!-- x -->", &["new".into()]).unwrap();
+        assert_eq!(s, "a\n<!-- x -->\nnew\n<!-- x -->\nb");
+        assert!(replace_section(&mut s, "<!-- y -->", &[]).is_err());
+    }
+}
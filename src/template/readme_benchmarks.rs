@@ -1,126 +1,385 @@
-/// Module that updates the readme me with timing information.
-/// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+/// Renders the benchmark section of the readme from stored timings.
+use std::{collections::HashMap, fmt::Display, str::FromStr, time::Duration};
 
-use crate::template::timings::Timings;
+use crate::template::event::Event;
+use crate::template::readme::{replace_section, Error};
+use crate::template::timings::{Timing, Timings};
 use crate::template::Day;
 
-static MARKER: &str = "<!--- benchmarking table --->";
+/// Optional columns of the benchmark table, next to day, part 1 and part 2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    /// Puzzle title from the downloaded puzzle description.
+    Title,
+    /// Time to read the input.
+    Parse,
+    /// Sum of parse, part 1 and part 2.
+    Total,
+    /// Bar showing the day's share of the total time.
+    Share,
+}
+
+impl Column {
+    const ALL: [Column; 4] = [Column::Title, Column::Parse, Column::Total, Column::Share];
 
-#[allow(dead_code)]
-#[derive(Debug)]
-pub enum Error {
-    Parser(String),
-    IO(io::Error),
+    fn name(self) -> &'static str {
+        match self {
+            Column::Title => "title",
+            Column::Parse => "parse",
+            Column::Total => "total",
+            Column::Share => "share",
+        }
+    }
 }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
+impl FromStr for Column {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Column::ALL
+            .into_iter()
+            .find(|column| column.name() == s)
+            .ok_or_else(|| {
+                format!(
+                    "unknown column `{s}`, expecting one of `title`, `parse`, `total` or `share`."
+                )
+            })
     }
 }
 
-pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+/// Parses a comma-separated list of columns, e.g. `title,total`.
+pub fn parse_columns(s: &str) -> Result<Vec<Column>, String> {
+    s.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::parse)
+        .collect()
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    Day,
+    /// Fastest day by total time first.
+    Fastest,
+    /// Slowest day by total time first.
+    Slowest,
+}
+
+impl FromStr for SortOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(SortOrder::Day),
+            "fastest" => Ok(SortOrder::Fastest),
+            "slowest" => Ok(SortOrder::Slowest),
+            x => Err(format!(
+                "unknown sort order `{x}`, expecting one of `day`, `fastest` or `slowest`."
+            )),
+        }
+    }
+}
+
+/// Unit all timings of the table are converted to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Unit {
+    /// Keeps the unit each timing was measured in.
+    Auto,
+    Nanos,
+    Micros,
+    Millis,
+    Seconds,
+}
+
+impl Unit {
+    fn nanos(self) -> f64 {
+        match self {
+            Unit::Auto | Unit::Nanos => 1.0,
+            Unit::Micros => 1e3,
+            Unit::Millis => 1e6,
+            Unit::Seconds => 1e9,
+        }
+    }
+
+    /// Formats a duration given in nanoseconds.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn format(self, nanos: f64) -> String {
+        match self {
+            Unit::Auto => format!("{:.1?}", Duration::from_nanos(nanos as u64)),
+            // keep small values in large units readable, e.g. `0.012ms` instead of `0.0ms`.
+            unit if nanos < unit.nanos() => format!("{:.3}{unit}", nanos / unit.nanos()),
+            unit => format!("{:.1}{unit}", nanos / unit.nanos()),
+        }
+    }
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+impl FromStr for Unit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Unit::Auto),
+            "ns" => Ok(Unit::Nanos),
+            "µs" | "us" => Ok(Unit::Micros),
+            "ms" => Ok(Unit::Millis),
+            "s" => Ok(Unit::Seconds),
+            x => Err(format!(
+                "unknown unit `{x}`, expecting one of `auto`, `ns`, `µs`, `ms` or `s`."
+            )),
+        }
+    }
+}
 
-    if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+impl Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Unit::Auto => write!(f, "auto"),
+            Unit::Nanos => write!(f, "ns"),
+            Unit::Micros => write!(f, "µs"),
+            Unit::Millis => write!(f, "ms"),
+            Unit::Seconds => write!(f, "s"),
+        }
     }
+}
 
-    let pos_start = matches
-        .first()
-        .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+/// How the benchmark table is rendered, set in the `[readme.benchmarks]` table of `aoc.toml`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableOptions {
+    pub columns: Vec<Column>,
+    pub sort: SortOrder,
+    pub unit: Unit,
+    /// Marks the fastest and the slowest day.
+    pub highlight: bool,
+}
 
-    let pos_end = matches
-        .last()
-        .map(|m| m.0 + m.1.len())
-        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+impl Default for TableOptions {
+    fn default() -> Self {
+        Self {
+            columns: vec![],
+            sort: SortOrder::Day,
+            unit: Unit::Auto,
+            highlight: false,
+        }
+    }
+}
+
+impl TableOptions {
+    /// Applies command-line overrides.
+    pub fn with_overrides(
+        mut self,
+        columns: Option<Vec<Column>>,
+        sort: Option<SortOrder>,
+        unit: Option<Unit>,
+        highlight: bool,
+    ) -> Self {
+        self.columns = columns.unwrap_or(self.columns);
+        self.sort = sort.unwrap_or(self.sort);
+        self.unit = unit.unwrap_or(self.unit);
+        self.highlight |= highlight;
+        self
+    }
+
+    fn has(&self, column: Column) -> bool {
+        self.columns.contains(&column)
+    }
+}
+
+/// Path of the solution module of `day`, which the benchmark table links to.
+#[must_use]
+pub fn get_path_for_module(day: Day) -> String {
+    format!("./src/solutions/day{day}.rs")
+}
 
-    Ok(TablePosition { pos_start, pos_end })
+/// Renders a bar of `width` characters, filled to `share` in eighths of a character.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn share_bar(share: f64, width: usize) -> String {
+    const PARTIAL: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+    let eighths = (share.clamp(0.0, 1.0) * (width * 8) as f64).round() as usize;
+    let mut bar = "█".repeat(eighths / 8);
+    bar.push(PARTIAL[eighths % 8]);
+    bar.trim_end().to_string()
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+fn construct_table(
+    prefix: &str,
+    event: &Event,
+    mut timings: Timings,
+    total_millis: f64,
+    options: &TableOptions,
+    titles: &HashMap<Day, String>,
+) -> Vec<String> {
     let header = format!("{prefix} Benchmarks");
 
+    match options.sort {
+        SortOrder::Day => timings.data.sort_by_key(|t| t.day),
+        SortOrder::Fastest => timings
+            .data
+            .sort_by(|a, b| a.total_nanos.total_cmp(&b.total_nanos)),
+        SortOrder::Slowest => timings
+            .data
+            .sort_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos)),
+    }
+
+    // highlighting needs at least two days to compare.
+    let timed: Vec<&Timing> = timings
+        .data
+        .iter()
+        .filter(|t| t.total_nanos > 0.0)
+        .collect();
+    let (fastest, slowest) = if options.highlight && timed.len() > 1 {
+        (
+            timed
+                .iter()
+                .min_by(|a, b| a.total_nanos.total_cmp(&b.total_nanos))
+                .map(|t| t.day),
+            timed
+                .iter()
+                .max_by(|a, b| a.total_nanos.total_cmp(&b.total_nanos))
+                .map(|t| t.day),
+        )
+    } else {
+        (None, None)
+    };
+
+    let format_part = |part: &Option<String>, nanos: Option<f64>| match (part, nanos) {
+        (Some(time), _) if options.unit == Unit::Auto => format!("`{time}`"),
+        (_, Some(nanos)) => format!("`{}`", options.unit.format(nanos)),
+        (Some(time), None) => format!("`{time}`"),
+        (None, None) => "`-`".into(),
+    };
+
+    let mut columns = vec!["Day"];
+    if options.has(Column::Title) {
+        columns.push("Title");
+    }
+    if options.has(Column::Parse) {
+        columns.push("Parse");
+    }
+    columns.extend(["Part 1", "Part 2"]);
+    if options.has(Column::Total) {
+        columns.push("Total");
+    }
+    if options.has(Column::Share) {
+        columns.push("Share");
+    }
+
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| {} |", columns.join(" | ")),
+        format!("| {} |", vec![":---:"; columns.len()].join(" | ")),
     ];
 
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    for timing in &timings.data {
+        let path = get_path_for_module(timing.day);
+        let mut day = format!("[Day {}]({})", timing.day.into_inner(), path);
+        if fastest == Some(timing.day) {
+            day.push_str(" ⚡");
+        } else if slowest == Some(timing.day) {
+            day.push_str(" 🐢");
+        }
+
+        let mut cells = vec![day];
+        if options.has(Column::Title) {
+            cells.push(titles.get(&timing.day).cloned().unwrap_or_default());
+        }
+        if options.has(Column::Parse) {
+            cells.push(format_part(&timing.parse, timing.parse_nanos));
+        }
+        cells.push(format_part(&timing.part_1, timing.part_1_nanos));
+        // single-part days have nothing to bench for part two.
+        cells.push(match timing.part_2 {
+            None if event.is_single_part(timing.day) => "n/a".into(),
+            ref part_2 => format_part(part_2, timing.part_2_nanos),
+        });
+        if options.has(Column::Total) {
+            cells.push(format!("`{}`", options.unit.format(timing.total_nanos)));
+        }
+        if options.has(Column::Share) {
+            let share = timing.total_nanos / (total_millis * 1e6);
+            let share = if share.is_finite() { share } else { 0.0 };
+            let bar = share_bar(share, 10);
+            cells.push(
+                format!("{bar} {:.1}%", share * 100.0)
+                    .trim_start()
+                    .to_string(),
+            );
+        }
+
+        lines.push(format!("| {} |", cells.join(" | ")));
     }
 
     lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
-
-    lines.join("\n")
-}
+    match options.unit {
+        Unit::Auto => lines.push(format!("**Total: {total_millis:.2}ms**")),
+        unit => lines.push(format!(
+            "**Total: {:.2}{unit}**",
+            total_millis * 1e6 / unit.nanos()
+        )),
+    }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
-    Ok(())
+    lines
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
-    fs::write(path, &readme)?;
-    Ok(())
+pub fn update_content(
+    s: &mut String,
+    marker: &str,
+    event: &Event,
+    timings: Timings,
+    total_millis: f64,
+    options: &TableOptions,
+    titles: &HashMap<Day, String>,
+) -> Result<(), Error> {
+    let table = construct_table("##", event, timings, total_millis, options, titles);
+    replace_section(s, marker, &table)
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use std::collections::HashMap;
+
+    use super::{update_content, Column, SortOrder, TableOptions, Unit};
+    use crate::day;
+    use crate::template::event::Event;
+    use crate::template::timings::{parse_duration, Timing, Timings};
+
+    const MARKER: &str = "<!--- benchmarking table --->";
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    parse_nanos: None,
+                    part_1_nanos: Some(10_000_000.0),
+                    part_2_nanos: Some(20_000_000.0),
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    parse_nanos: None,
+                    part_1_nanos: Some(30_000_000.0),
+                    part_2_nanos: Some(40_000_000.0),
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
+                    parse: None,
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
+                    parse_nanos: None,
+                    part_1_nanos: Some(40_000_000.0),
+                    part_2_nanos: Some(50_000_000.0),
                     total_nanos: 9e+10,
                 },
             ],
@@ -131,36 +390,118 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            MARKER,
+            &Event::builtin(2024),
+            get_mock_timings(),
+            190.0,
+            &TableOptions::default(),
+            &HashMap::new(),
+        )
+        .unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            MARKER,
+            &Event::builtin(2024),
+            get_mock_timings(),
+            190.0,
+            &TableOptions::default(),
+            &HashMap::new(),
+        )
+        .unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            MARKER,
+            &Event::builtin(2024),
+            get_mock_timings(),
+            190.0,
+            &TableOptions::default(),
+            &HashMap::new(),
+        )
+        .unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            MARKER,
+            &Event::builtin(2024),
+            get_mock_timings(),
+            190.0,
+            &TableOptions::default(),
+            &HashMap::new(),
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            MARKER,
+            &Event::builtin(2024),
+            get_mock_timings(),
+            190.0,
+            &TableOptions::default(),
+            &HashMap::new(),
+        )
+        .unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
 
+    #[test]
+    fn formats_single_part_days() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        let timings = Timings {
+            data: vec![Timing {
+                day: day!(25),
+                parse: None,
+                part_1: Some("1ms".into()),
+                part_2: None,
+                parse_nanos: None,
+                part_1_nanos: Some(1_000_000.0),
+                part_2_nanos: None,
+                total_nanos: 1e+6,
+            }],
+        };
+        update_content(
+            &mut s,
+            MARKER,
+            &Event::builtin(2024),
+            timings,
+            1.0,
+            &TableOptions::default(),
+            &HashMap::new(),
+        )
+        .unwrap();
+        assert!(s.contains("| [Day 25](./src/solutions/day25.rs) | `1ms` | n/a |"));
+    }
+
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            MARKER,
+            &Event::builtin(2024),
+            get_mock_timings(),
+            190.0,
+            &TableOptions::default(),
+            &HashMap::new(),
+        )
+        .unwrap();
         let expected = [
             "foo",
             "bar",
@@ -168,10 +509,10 @@ mod tests {
             "## Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](./src/solutions/day01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/solutions/day02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/solutions/day04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +521,61 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn renders_table_options() {
+        let timing = |day, parse: Option<&str>, part_1: &str, part_2: Option<&str>, total| Timing {
+            day,
+            parse: parse.map(Into::into),
+            part_1: Some(part_1.into()),
+            part_2: part_2.map(Into::into),
+            parse_nanos: parse.and_then(parse_duration),
+            part_1_nanos: parse_duration(part_1),
+            part_2_nanos: part_2.and_then(parse_duration),
+            total_nanos: total,
+        };
+        let timings = Timings {
+            data: vec![
+                timing(day!(1), Some("1.0µs"), "10.0µs", Some("20.0µs"), 31_000.0),
+                timing(day!(2), None, "1.0ms", Some("2.0ms"), 3_000_000.0),
+                timing(day!(3), None, "500.0ns", None, 500.0),
+            ],
+        };
+        let options = TableOptions {
+            columns: vec![Column::Title, Column::Parse, Column::Total, Column::Share],
+            sort: SortOrder::Slowest,
+            unit: Unit::Micros,
+            highlight: true,
+        };
+        let titles = HashMap::from([(day!(1), "Historian Hysteria".to_string())]);
+
+        let mut s = format!("{MARKER}{MARKER}");
+        let total_millis = timings.total_millis();
+        update_content(
+            &mut s,
+            MARKER,
+            &Event::builtin(2024),
+            timings,
+            total_millis,
+            &options,
+            &titles,
+        )
+        .unwrap();
+
+        let expected = [
+            MARKER,
+            "## Benchmarks",
+            "",
+            "| Day | Title | Parse | Part 1 | Part 2 | Total | Share |",
+            "| :---: | :---: | :---: | :---: | :---: | :---: | :---: |",
+            "| [Day 2](./src/solutions/day02.rs) 🐢 |  | `-` | `1000.0µs` | `2000.0µs` | `3000.0µs` | █████████▉ 99.0% |",
+            "| [Day 1](./src/solutions/day01.rs) | Historian Hysteria | `1.0µs` | `10.0µs` | `20.0µs` | `31.0µs` | ▏ 1.0% |",
+            "| [Day 3](./src/solutions/day03.rs) ⚡ |  | `-` | `0.500µs` | `-` | `0.500µs` | 0.0% |",
+            "",
+            "**Total: 3031.50µs**",
+            MARKER,
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
 }
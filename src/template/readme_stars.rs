@@ -0,0 +1,131 @@
+/// Renders the stars section of the readme from the answer ledger.
+use std::collections::HashMap;
+
+use crate::template::answers::Ledger;
+use crate::template::event::Event;
+use crate::template::readme::{replace_section, Error};
+use crate::template::Day;
+
+fn construct_table(
+    prefix: &str,
+    event: &Event,
+    days: &[Day],
+    ledger: &Ledger,
+    titles: &HashMap<Day, String>,
+) -> Vec<String> {
+    let star = |solved: bool| if solved { "⭐" } else { " " };
+
+    let mut lines: Vec<String> = vec![
+        format!("{prefix} {} Results", event.year),
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    for &day in days {
+        let title = match titles.get(&day) {
+            Some(title) => format!("Day {}: {title}", day.into_inner()),
+            None => format!("Day {}", day.into_inner()),
+        };
+        lines.push(format!(
+            "| [{title}](https://adventofcode.com/{}/day/{}) | {} | {} |",
+            event.year,
+            day.into_inner(),
+            star(ledger.has_star(event, day, 1)),
+            star(ledger.has_star(event, day, 2))
+        ));
+    }
+
+    let stars = ledger.star_count(event);
+    lines.push(String::new());
+    lines.push(format!(
+        "**Stars: {stars} / {}**",
+        usize::from(event.len()) * 2
+    ));
+
+    lines
+}
+
+pub fn update_content(
+    s: &mut String,
+    marker: &str,
+    event: &Event,
+    days: &[Day],
+    ledger: &Ledger,
+    titles: &HashMap<Day, String>,
+) -> Result<(), Error> {
+    let table = construct_table("##", event, days, ledger, titles);
+    replace_section(s, marker, &table)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::update_content;
+    use crate::day;
+    use crate::template::answers::{Ledger, Verdict};
+    use crate::template::event::Event;
+
+    const MARKER: &str = "<!--- advent_readme_stars table --->";
+
+    #[test]
+    fn renders_stars_from_ledger() {
+        let mut ledger = Ledger::default();
+        ledger.record(day!(1), 1, "11", Verdict::Incorrect);
+        ledger.record(day!(1), 1, "42", Verdict::Correct);
+        ledger.record(day!(1), 2, "7", Verdict::AlreadySolved);
+        ledger.record(day!(2), 1, "3", Verdict::Correct);
+        let titles = HashMap::from([(day!(1), "Historian Hysteria".to_string())]);
+
+        let mut s = format!("foo\n{MARKER}\n{MARKER}\nbar");
+        update_content(
+            &mut s,
+            MARKER,
+            &Event::builtin(2024),
+            &[day!(1), day!(2), day!(3)],
+            &ledger,
+            &titles,
+        )
+        .unwrap();
+
+        let expected = [
+            "foo",
+            MARKER,
+            "## 2024 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1: Historian Hysteria](https://adventofcode.com/2024/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2024/day/2) | ⭐ |   |",
+            "| [Day 3](https://adventofcode.com/2024/day/3) |   |   |",
+            "",
+            "**Stars: 3 / 50**",
+            MARKER,
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn awards_last_star_for_single_part_days() {
+        let event = Event {
+            end: "2024-12-02".parse().unwrap(),
+            ..Event::builtin(2024)
+        };
+        let mut ledger = Ledger::default();
+        ledger.record(day!(2), 1, "1", Verdict::Correct);
+
+        let mut s = format!("{MARKER}{MARKER}");
+        let days = [day!(1), day!(2)];
+        update_content(&mut s, MARKER, &event, &days, &ledger, &HashMap::new()).unwrap();
+        assert!(s.contains("| [Day 2](https://adventofcode.com/2024/day/2) | ⭐ |   |"));
+
+        ledger.record(day!(1), 1, "1", Verdict::Correct);
+        ledger.record(day!(1), 2, "2", Verdict::Correct);
+        update_content(&mut s, MARKER, &event, &days, &ledger, &HashMap::new()).unwrap();
+        assert!(s.contains("| [Day 2](https://adventofcode.com/2024/day/2) | ⭐ | ⭐ |"));
+        assert!(s.contains("**Stars: 4 / 4**"));
+    }
+}
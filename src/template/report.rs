@@ -0,0 +1,369 @@
+/// Renders a self-contained HTML report of benchmarks and progress.
+/// Styles and charts are inlined as CSS and SVG, so the file can be shared as is.
+use std::{collections::HashMap, fmt::Write, time::Duration};
+
+use crate::template::answers::Ledger;
+use crate::template::event::Event;
+use crate::template::timings::{History, Timing, Timings};
+use crate::template::Day;
+
+const STYLE: &str = "
+body { background: #0f0f23; color: #cccccc; font-family: 'Source Code Pro', monospace; margin: 2em auto; max-width: 60em; }
+h1, h2 { color: #00cc00; font-weight: normal; }
+a { color: inherit; text-decoration: none; }
+table { border-collapse: collapse; width: 100%; }
+th, td { padding: 0.3em 0.6em; text-align: left; vertical-align: middle; }
+tr:nth-child(even) { background: #10101a; }
+svg text { fill: #cccccc; font-size: 11px; font-family: inherit; }
+.calendar { display: grid; grid-template-columns: repeat(7, 1fr); gap: 0.4em; }
+.day { border: 1px solid #333340; padding: 0.5em; text-align: center; }
+.day .stars { display: block; color: #333340; }
+.stars-1 .stars { color: #9999cc; }
+.stars-2 .stars { color: #ffff66; }
+.parse { fill: #666677; }
+.part-1 { fill: #9999cc; }
+.part-2 { fill: #ffff66; }
+.total { fill: #00cc00; }
+.grid { stroke: #333340; }
+.sparkline { fill: none; stroke: #00cc00; stroke-width: 1.5; }
+";
+
+/// Everything the report is rendered from.
+pub struct Report<'a> {
+    pub event: &'a Event,
+    pub timings: &'a Timings,
+    pub ledger: &'a Ledger,
+    pub history: &'a History,
+    pub titles: &'a HashMap<Day, String>,
+}
+
+impl Report<'_> {
+    pub fn render(&self) -> String {
+        let year = self.event.year;
+        let mut html = String::new();
+
+        let _ = writeln!(html, "<!DOCTYPE html>");
+        let _ = writeln!(html, "<html lang=\"en\">");
+        let _ = writeln!(html, "<head>");
+        let _ = writeln!(html, "<meta charset=\"utf-8\">");
+        let _ = writeln!(html, "<title>Advent of Code {year}</title>");
+        let _ = writeln!(html, "<style>{STYLE}</style>");
+        let _ = writeln!(html, "</head>");
+        let _ = writeln!(html, "<body>");
+        let _ = writeln!(html, "<h1>Advent of Code {year}</h1>");
+        let _ = writeln!(
+            html,
+            "<p>{} / {} stars, {} total run time.</p>",
+            self.ledger.star_count(self.event),
+            usize::from(self.event.len()) * 2,
+            format_nanos(self.timings.total_millis() * 1e6)
+        );
+
+        let _ = writeln!(html, "<h2>Stars</h2>");
+        html.push_str(&self.star_calendar());
+
+        if self.timings.data.iter().any(|t| t.total_nanos > 0.0) {
+            let _ = writeln!(html, "<h2>Total time per day (log scale)</h2>");
+            html.push_str(&log_totals(&self.timings.data));
+            let _ = writeln!(html, "<h2>Timings</h2>");
+            html.push_str(&self.timing_table());
+        }
+
+        let _ = writeln!(html, "</body>");
+        let _ = writeln!(html, "</html>");
+        html
+    }
+
+    fn star_calendar(&self) -> String {
+        let mut html = String::from("<div class=\"calendar\">\n");
+        for day in self.event.days() {
+            let stars = (1..=2)
+                .filter(|part| self.ledger.has_star(self.event, day, *part))
+                .count();
+            let title = match self.titles.get(&day) {
+                Some(title) => format!("Day {}: {}", day.into_inner(), escape(title)),
+                None => format!("Day {}", day.into_inner()),
+            };
+            let _ = writeln!(
+                html,
+                "<a class=\"day stars-{stars}\" href=\"https://adventofcode.com/{}/day/{}\" title=\"{title}\">{}<span class=\"stars\">★★</span></a>",
+                self.event.year,
+                day.into_inner(),
+                day.into_inner()
+            );
+        }
+        html.push_str("</div>\n");
+        html
+    }
+
+    fn timing_table(&self) -> String {
+        let mut html = String::from(
+            "<table>\n<tr><th>Day</th><th>Title</th><th>Parts</th><th>Total</th><th>History</th></tr>\n",
+        );
+        for timing in &self.timings.data {
+            let title = self
+                .titles
+                .get(&timing.day)
+                .map(|t| escape(t))
+                .unwrap_or_default();
+            let history = sparkline(&self.history.totals(timing.day)).unwrap_or_default();
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td>{title}</td><td>{}</td><td>{}</td><td>{history}</td></tr>",
+                timing.day,
+                part_bars(timing),
+                format_nanos(timing.total_nanos)
+            );
+        }
+        html.push_str("</table>\n");
+        html
+    }
+}
+
+/// Horizontal bars of the parse and part timings of a day, relative to its slowest part.
+#[allow(clippy::cast_precision_loss)]
+fn part_bars(timing: &Timing) -> String {
+    let parts: Vec<(&str, &str, f64)> = [
+        ("parse", "parse", timing.parse_nanos),
+        ("part-1", "part 1", timing.part_1_nanos),
+        ("part-2", "part 2", timing.part_2_nanos),
+    ]
+    .into_iter()
+    .filter_map(|(class, label, nanos)| Some((class, label, nanos?)))
+    .collect();
+
+    let max = parts.iter().map(|p| p.2).fold(0.0, f64::max);
+    if parts.is_empty() || max <= 0.0 {
+        return String::new();
+    }
+
+    let row = 14.0;
+    let mut svg = format!(
+        "<svg width=\"280\" height=\"{}\" xmlns=\"http://www.w3.org/2000/svg\">",
+        parts.len() as f64 * row
+    );
+    for (i, (class, label, nanos)) in parts.iter().enumerate() {
+        let y = i as f64 * row;
+        let width = (nanos / max * 150.0).max(1.0);
+        let _ = write!(
+            svg,
+            "<text x=\"0\" y=\"{:.1}\">{label}</text><rect class=\"{class}\" x=\"50\" y=\"{:.1}\" width=\"{width:.1}\" height=\"10\"/><text x=\"{:.1}\" y=\"{:.1}\">{}</text>",
+            y + 10.0,
+            y + 1.0,
+            54.0 + width,
+            y + 10.0,
+            format_nanos(*nanos)
+        );
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Vertical bars of the total time of every day on a logarithmic axis.
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+fn log_totals(timings: &[Timing]) -> String {
+    let timed: Vec<&Timing> = timings.iter().filter(|t| t.total_nanos > 0.0).collect();
+    let logs: Vec<f64> = timed
+        .iter()
+        .map(|t| t.total_nanos.max(1.0).log10())
+        .collect();
+    let low = logs.iter().copied().fold(f64::INFINITY, f64::min).floor();
+    let high = logs
+        .iter()
+        .copied()
+        .fold(f64::NEG_INFINITY, f64::max)
+        .ceil();
+    let high = if high > low { high } else { low + 1.0 };
+
+    let (left, top, height, pitch) = (60.0, 10.0, 180.0, 24.0);
+    let y = |log: f64| top + height - (log - low) / (high - low) * height;
+    let width = left + timed.len() as f64 * pitch + 10.0;
+
+    let mut svg = format!(
+        "<svg width=\"{width:.0}\" height=\"{:.0}\" xmlns=\"http://www.w3.org/2000/svg\">",
+        top + height + 20.0
+    );
+    for decade in low as i32..=high as i32 {
+        let line_y = y(f64::from(decade));
+        let _ = write!(
+            svg,
+            "<line class=\"grid\" x1=\"{left}\" x2=\"{width:.0}\" y1=\"{line_y:.1}\" y2=\"{line_y:.1}\"/><text x=\"0\" y=\"{:.1}\">{}</text>",
+            line_y + 4.0,
+            format_nanos(10_f64.powi(decade))
+        );
+    }
+    for (i, (timing, log)) in timed.iter().zip(&logs).enumerate() {
+        let x = left + i as f64 * pitch + 4.0;
+        let bar_y = y(*log);
+        let _ = write!(
+            svg,
+            "<rect class=\"total\" x=\"{x:.1}\" y=\"{bar_y:.1}\" width=\"16\" height=\"{:.1}\"><title>Day {}: {}</title></rect><text x=\"{x:.1}\" y=\"{:.1}\">{}</text>",
+            top + height - bar_y,
+            timing.day,
+            format_nanos(timing.total_nanos),
+            top + height + 14.0,
+            timing.day
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Line of how the total time of a day changed, `None` without at least two stored runs.
+#[allow(clippy::cast_precision_loss)]
+fn sparkline(totals: &[f64]) -> Option<String> {
+    if totals.len() < 2 {
+        return None;
+    }
+
+    let min = totals.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = totals.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = if max > min { max - min } else { 1.0 };
+    let step = 100.0 / (totals.len() - 1) as f64;
+
+    let points: Vec<String> = totals
+        .iter()
+        .enumerate()
+        .map(|(i, total)| {
+            format!(
+                "{:.1},{:.1}",
+                i as f64 * step,
+                22.0 - (total - min) / range * 20.0
+            )
+        })
+        .collect();
+
+    Some(format!(
+        "<svg width=\"100\" height=\"24\" xmlns=\"http://www.w3.org/2000/svg\"><polyline class=\"sparkline\" points=\"{}\"/></svg>",
+        points.join(" ")
+    ))
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos as u64))
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{sparkline, Report};
+    use crate::day;
+    use crate::template::answers::{Ledger, Verdict};
+    use crate::template::event::Event;
+    use crate::template::timings::{History, HistoryEntry, Timing, Timings};
+
+    fn timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    parse: Some("1.0µs".into()),
+                    part_1: Some("10.0µs".into()),
+                    part_2: Some("100.0µs".into()),
+                    parse_nanos: Some(1_000.0),
+                    part_1_nanos: Some(10_000.0),
+                    part_2_nanos: Some(100_000.0),
+                    total_nanos: 111_000.0,
+                },
+                Timing {
+                    day: day!(2),
+                    parse: None,
+                    part_1: Some("2.0ms".into()),
+                    part_2: None,
+                    parse_nanos: None,
+                    part_1_nanos: Some(2_000_000.0),
+                    part_2_nanos: None,
+                    total_nanos: 2_000_000.0,
+                },
+            ],
+        }
+    }
+
+    fn render(
+        ledger: &Ledger,
+        history: &History,
+        titles: &HashMap<crate::template::Day, String>,
+    ) -> String {
+        Report {
+            event: &Event::builtin(2024),
+            timings: &timings(),
+            ledger,
+            history,
+            titles,
+        }
+        .render()
+    }
+
+    #[test]
+    fn renders_self_contained_document() {
+        let html = render(&Ledger::default(), &History::default(), &HashMap::new());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<style>"));
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("<link"));
+        assert!(!html.contains("src=\""));
+        assert!(html.contains("<p>0 / 50 stars, 2.1ms total run time.</p>"));
+    }
+
+    #[test]
+    fn renders_star_calendar() {
+        let mut ledger = Ledger::default();
+        ledger.record(day!(1), 1, "1", Verdict::Correct);
+        ledger.record(day!(1), 2, "2", Verdict::Correct);
+        ledger.record(day!(2), 1, "3", Verdict::Correct);
+        let titles = HashMap::from([(day!(1), "<Historian> Hysteria".to_string())]);
+
+        let html = render(&ledger, &History::default(), &titles);
+        assert!(html.contains("<a class=\"day stars-2\" href=\"https://adventofcode.com/2024/day/1\" title=\"Day 1: &lt;Historian&gt; Hysteria\">"));
+        assert!(html.contains("<a class=\"day stars-1\" href=\"https://adventofcode.com/2024/day/2\" title=\"Day 2\">"));
+        assert_eq!(html.matches("class=\"day stars-0\"").count(), 23);
+        assert!(html.contains("<p>3 / 50 stars"));
+    }
+
+    #[test]
+    fn renders_timing_charts() {
+        let html = render(&Ledger::default(), &History::default(), &HashMap::new());
+        // totals span 111µs to 2ms, so the log axis covers 100µs to 10ms.
+        assert!(html.contains(">100.0µs</text>"));
+        assert!(html.contains(">1.0ms</text>"));
+        assert!(html.contains(">10.0ms</text>"));
+        assert!(html.contains("<title>Day 02: 2.0ms</title>"));
+        // parts are scaled to the slowest part of their day.
+        assert!(html.contains(
+            "<rect class=\"part-2\" x=\"50\" y=\"29.0\" width=\"150.0\" height=\"10\"/>"
+        ));
+        assert!(html
+            .contains("<rect class=\"part-1\" x=\"50\" y=\"1.0\" width=\"150.0\" height=\"10\"/>"));
+    }
+
+    #[test]
+    fn renders_sparklines_with_history() {
+        let entry = |total_nanos| HistoryEntry {
+            day: day!(1),
+            timestamp: 0,
+            total_nanos,
+        };
+
+        let html = render(&Ledger::default(), &History::default(), &HashMap::new());
+        assert!(!html.contains("<polyline"));
+
+        let history = History {
+            data: vec![entry(300.0), entry(100.0), entry(200.0)],
+        };
+        let html = render(&Ledger::default(), &history, &HashMap::new());
+        assert_eq!(html.matches("<polyline").count(), 1);
+        assert_eq!(
+            sparkline(&[300.0, 100.0, 200.0]).unwrap(),
+            "<svg width=\"100\" height=\"24\" xmlns=\"http://www.w3.org/2000/svg\"><polyline class=\"sparkline\" points=\"0.0,2.0 50.0,22.0 100.0,12.0\"/></svg>"
+        );
+    }
+}
@@ -1,14 +1,20 @@
-use std::{collections::HashSet, io};
+use std::{collections::HashSet, io, time::Duration};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Day, Status, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    profile: child_commands::Profile,
+    is_timed: bool,
+    timeout: Option<Duration>,
+) -> (Option<Timings>, Status) {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut status = Status::Success;
 
     let mut need_space = false;
 
@@ -24,9 +30,20 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let (output, day_status) =
+                match child_commands::run_solution(day, is_timed, profile, timeout) {
+                    Ok(result) => result,
+                    Err(e) => {
+                        eprintln!("Failed to run solution: {e:?}");
+                        (vec![], Status::Failure)
+                    }
+                };
+
+            status = status.and(day_status);
 
-            if output.is_empty() {
+            if !day_status.is_success() {
+                println!("Failed: {day_status}.");
+            } else if output.is_empty() {
                 println!("Not solved.");
             } else {
                 let val = child_commands::parse_exec_time(&output, day);
@@ -40,9 +57,9 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
+        (Some(timings), status)
     } else {
-        None
+        (None, status)
     }
 }
 
@@ -68,38 +85,204 @@ pub fn get_path_for_bin(day: Day) -> String {
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
     use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use crate::template::matrix::{BuildConfig, Experiment};
+    use crate::template::timings::parse_duration;
+    use crate::template::{Day, Status};
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        env,
+        io::{self, BufRead, BufReader},
+        path::{Path, PathBuf},
+        process::{Child, Command, Stdio},
         thread,
+        time::{Duration, Instant},
     };
 
+    /// Cargo profile a solution binary is built with.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Profile {
+        Debug,
+        Release,
+        /// Release build with debug symbols and the DHAT heap profiler enabled.
+        Dhat,
+        /// Release build that records `span!`s.
+        Spans,
+        /// Release build with the features of an experiment, see `cargo time --matrix`.
+        Experiment(Experiment),
+        /// Build with the profile and flags of a configuration, see `cargo time --profiles`.
+        Build(BuildConfig),
+    }
+
+    impl Profile {
+        fn cargo_args(self) -> &'static [&'static str] {
+            match self {
+                Profile::Debug => &[],
+                Profile::Release => &["--release"],
+                Profile::Dhat => &["--profile", "dhat", "--features", "dhat-heap"],
+                Profile::Spans => &["--release", "--features", "spans"],
+                Profile::Experiment(experiment) => experiment.cargo_args(),
+                Profile::Build(config) => config.cargo_args(),
+            }
+        }
+
+        /// Folder inside the target directory that holds this profile's artifacts.
+        fn target_folder(self) -> &'static str {
+            match self {
+                Profile::Debug => "debug",
+                Profile::Release | Profile::Spans | Profile::Experiment(_) => "release",
+                Profile::Dhat => "dhat",
+                Profile::Build(config) => config.cargo_profile(),
+            }
+        }
+
+        /// Flags appended to `RUSTFLAGS` when building.
+        fn rustflags(self) -> Option<&'static str> {
+            match self {
+                Profile::Build(config) => config.rustflags(),
+                _ => None,
+            }
+        }
+    }
+
+    /// Resolves the cargo target directory.
+    /// The template binary itself lives in `<target>/<profile>/`, so this also works for custom target directories.
+    fn target_dir() -> PathBuf {
+        if let Some(dir) = env::var_os("CARGO_TARGET_DIR") {
+            return PathBuf::from(dir);
+        }
+
+        env::current_exe()
+            .ok()
+            .and_then(|exe| Some(exe.parent()?.parent()?.to_path_buf()))
+            .unwrap_or_else(|| PathBuf::from("target"))
+    }
+
+    /// Build the solution bin for a given day, returning the path to the executable.
+    pub fn build_solution(day: Day, profile: Profile, quiet: bool) -> Result<PathBuf, Status> {
+        let day_padded = day.to_string();
+        let mut args = vec!["build", "--bin", &day_padded];
+        args.extend(profile.cargo_args());
+
+        if quiet {
+            args.push("--quiet");
+        }
+
+        let mut cmd = Command::new("cargo");
+        if let Some(flags) = profile.rustflags() {
+            let rustflags = match env::var("RUSTFLAGS") {
+                Ok(existing) if !existing.trim().is_empty() => format!("{existing} {flags}"),
+                _ => flags.to_string(),
+            };
+            cmd.env("RUSTFLAGS", rustflags);
+        }
+
+        let exit = cmd
+            .args(&args)
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()
+            .map_err(|e| {
+                eprintln!("Failed to call cargo: {e}");
+                Status::Failure
+            })?;
+
+        if !exit.success() {
+            return Err(Status::BuildFailed);
+        }
+
+        Ok(target_dir()
+            .join(profile.target_folder())
+            .join(format!("{day}{}", env::consts::EXE_SUFFIX)))
+    }
+
+    /// Build the solution bin for a given day and run it with `args`, forwarding its output.
+    pub fn run_interactive(
+        day: Day,
+        profile: Profile,
+        args: &[String],
+        timeout: Option<Duration>,
+    ) -> Status {
+        match build_solution(day, profile, false) {
+            Ok(binary) => run_binary(&binary, args, timeout),
+            Err(status) => status,
+        }
+    }
+
+    /// Run a built solution bin with `args`, forwarding its output.
+    pub fn run_binary(binary: &Path, args: &[String], timeout: Option<Duration>) -> Status {
+        let mut cmd = match Command::new(binary)
+            .args(args)
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .spawn()
+        {
+            Ok(cmd) => cmd,
+            Err(e) => {
+                eprintln!("Failed to run \"{}\": {e}", binary.display());
+                return Status::Failure;
+            }
+        };
+
+        match wait(&mut cmd, timeout) {
+            Ok(status) => status,
+            Err(e) => {
+                eprintln!("Failed to wait for solution: {e}");
+                Status::Failure
+            }
+        }
+    }
+
+    /// Wait for a child process, killing it if it does not finish within `timeout`.
+    fn wait(child: &mut Child, timeout: Option<Duration>) -> io::Result<Status> {
+        let Some(timeout) = timeout else {
+            return Ok(Status::from_exit(child.wait()?));
+        };
+
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            if let Some(exit) = child.try_wait()? {
+                return Ok(Status::from_exit(exit));
+            }
+
+            if Instant::now() >= deadline {
+                child.kill()?;
+                child.wait()?;
+                eprintln!("Killed solution after exceeding the timeout of {timeout:?}.");
+                return Ok(Status::Timeout);
+            }
+
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
     /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    pub fn run_solution(
+        day: Day,
+        is_timed: bool,
+        profile: Profile,
+        timeout: Option<Duration>,
+    ) -> Result<(Vec<String>, Status), Error> {
         // skip command invocation for days that have not been scaffolded yet.
         if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+            return Ok((vec![], Status::Success));
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let binary = match build_solution(day, profile, true) {
+            Ok(binary) => binary,
+            Err(status) => return Ok((vec![], status)),
+        };
 
-        if is_release {
-            args.push("--release");
-        }
+        let mut args = vec![];
 
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
         // spawn child command with piped stdout/stderr.
         // forward output to stdout/stderr while grabbing stdout lines.
 
-        let mut cmd = Command::new("cargo")
+        let mut cmd = Command::new(binary)
             .args(&args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -108,31 +291,37 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
-
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+        let stderr_thread = thread::spawn(move || {
+            stderr.lines().map_while(Result::ok).for_each(|line| {
+                eprintln!("{line}");
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
+        let stdout_thread = thread::spawn(move || {
+            stdout
+                .lines()
+                .map_while(Result::ok)
+                .inspect(|line| println!("{line}"))
+                .collect::<Vec<String>>()
+        });
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let status = wait(&mut cmd, timeout)?;
 
-        Ok(output)
+        let output = stdout_thread.join().unwrap_or_default();
+        stderr_thread.join().ok();
+
+        Ok((output, status))
     }
 
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
         let mut timings = super::Timing {
             day,
+            parse: None,
             part_1: None,
             part_2: None,
+            parse_nanos: None,
+            part_1_nanos: None,
+            part_2_nanos: None,
             total_nanos: 0_f64,
         };
 
@@ -152,10 +341,18 @@ pub mod child_commands {
                 Some((part, timing_str, nanos))
             })
             .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
+                // lines may start with the runner's progress indicator, e.g. ` > benching`.
+                if part.ends_with("Parse") {
+                    timings.parse = Some(timing_str.into());
+                    timings.parse_nanos = Some(nanos);
+                    // the parts parse the input themselves, so their times already include it.
+                    return;
+                } else if part.contains("Part 1") {
                     timings.part_1 = Some(timing_str.into());
+                    timings.part_1_nanos = Some(nanos);
                 } else if part.contains("Part 2") {
                     timings.part_2 = Some(timing_str.into());
+                    timings.part_2_nanos = Some(nanos);
                 }
 
                 timings.total_nanos += nanos;
@@ -164,27 +361,17 @@ pub mod child_commands {
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
     fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
         let str_timing = line
             .split(" samples)")
             .next()?
             .split('(')
-            .last()?
+            .next_back()?
             .split('@')
             .next()?
             .trim();
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+        let parsed_timing = parse_duration(str_timing)?;
 
         Some((str_timing, parsed_timing))
     }
@@ -224,6 +411,24 @@ pub mod child_commands {
             assert_eq!(res.part_2.unwrap(), "74.13ms");
         }
 
+        #[test]
+        fn parses_parse_times() {
+            let res = parse_exec_time(
+                &[
+                    " > \x1b[3mbenching\x1b[0mParse: (1.5µs @ 1000 samples)".into(),
+                    "Part 1: 0 (10ns @ 100000 samples)".into(),
+                    "Part 2: ✖        ".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 10_f64);
+            assert_eq!(res.parse.unwrap(), "1.5µs");
+            assert_eq!(res.part_1.unwrap(), "10ns");
+            assert_eq!(res.parse_nanos, Some(1500.0));
+            assert_eq!(res.part_1_nanos, Some(10.0));
+            assert_eq!(res.part_2_nanos, None);
+        }
+
         #[test]
         fn parses_with_patterns_in_input() {
             let res = parse_exec_time(
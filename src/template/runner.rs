@@ -1,33 +1,199 @@
 /// Encapsulates code that interacts with solution functions.
+use std::cell::RefCell;
+use std::cmp;
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{stdin, stdout, Write};
+use std::str::FromStr;
+use std::thread;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
 
+use crate::template::answers::{Ledger, Verdict};
+use crate::template::aoc_cli::AocCommandError;
+use crate::template::config::{self, SubmitPolicy};
+use crate::template::fuzz::FuzzOptions;
+use crate::template::scale::ScaleOptions;
+use crate::template::spans::{self, Tree};
+use crate::template::stats::{self, Milestone};
+use crate::template::viz::{self, VizOptions};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{aoc_cli, Day, Status, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Gives up on a submission after this many cooldowns.
+const MAX_SUBMIT_ATTEMPTS: usize = 5;
+
+/// Which answers `--submit` sends to the puzzle server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Submit {
+    Part(u8),
+    /// Submits part one, then part two once part one is accepted.
+    Auto,
+}
+
+impl FromStr for Submit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Submit::Part(1)),
+            "2" => Ok(Submit::Part(2)),
+            "auto" => Ok(Submit::Auto),
+            _ => Err(format!("invalid value `{s}`, expecting 1, 2 or auto.")),
+        }
+    }
+}
+
+impl Display for Submit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Submit::Part(part) => write!(f, "{part}"),
+            Submit::Auto => write!(f, "auto"),
+        }
+    }
+}
+
+/// Flags passed to a solution binary by `solve`, `all` and `time`.
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    /// Bench each part instead of running it once.
+    pub time: bool,
+    pub submit: Option<Submit>,
+    /// Analyze how each part scales with the input size instead of solving, see `cargo scale`.
+    pub scale: Option<ScaleOptions>,
+    /// Run the parts on generated inputs instead of solving, see `cargo fuzz-day`.
+    pub fuzz: Option<FuzzOptions>,
+    /// Record the frames of each part and export them, see `cargo solve --viz`.
+    pub viz: Option<VizOptions>,
+}
+
+impl RunOptions {
+    pub fn from_args() -> Result<Self, String> {
+        let mut args = pico_args::Arguments::from_env();
+        let err = |e: pico_args::Error| e.to_string();
+
+        let scale = if args.contains("--scale") {
+            let defaults = ScaleOptions::default();
+            Some(ScaleOptions {
+                steps: args
+                    .opt_value_from_str("--steps")
+                    .map_err(err)?
+                    .unwrap_or(defaults.steps),
+                max: args.opt_value_from_str("--max").map_err(err)?,
+                part: args.opt_value_from_str("--part").map_err(err)?,
+            })
+        } else {
+            None
+        };
+
+        let fuzz = if args.contains("--fuzz") {
+            let defaults = FuzzOptions::default();
+            Some(FuzzOptions {
+                seed: args
+                    .opt_value_from_str("--seed")
+                    .map_err(err)?
+                    .unwrap_or(defaults.seed),
+                count: args
+                    .opt_value_from_str("--count")
+                    .map_err(err)?
+                    .unwrap_or(defaults.count),
+                size: args
+                    .opt_value_from_str("--size")
+                    .map_err(err)?
+                    .unwrap_or(defaults.size),
+                save: args.contains("--save"),
+            })
+        } else {
+            None
+        };
+
+        let viz = match args.opt_value_from_str("--viz").map_err(err)? {
+            Some(prefix) => Some(VizOptions {
+                prefix,
+                format: args
+                    .opt_value_from_str("--viz-format")
+                    .map_err(err)?
+                    .unwrap_or_default(),
+            }),
+            None => None,
+        };
+
+        Ok(Self {
+            time: args.contains("--time"),
+            submit: args.opt_value_from_str("--submit").map_err(err)?,
+            scale,
+            fuzz,
+            viz,
+        })
+    }
+}
+
+/// Runs a solution part and prints its result, returning the answer if there is one.
+pub fn run_part<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    part: u8,
+    options: &RunOptions,
+) -> Option<String> {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    // spans are reported for the first run only, samples of the benchmark would add up.
+    spans::take();
+    if options.viz.is_some() {
+        viz::start();
+    }
+    let tree = RefCell::new(Tree::default());
+    let recording = RefCell::new(None);
+    let (result, duration, samples) = run_timed(func, input, options.time, |result| {
+        tree.replace(spans::take());
+        recording.replace(viz::take());
+        print_result(result, &part_str, "");
+    });
+    spans::take();
 
     print_result(&result, &part_str, &format_duration(&duration, samples));
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    let tree = tree.into_inner();
+    if !tree.is_empty() {
+        print!("{}", tree.render());
+    }
+
+    if let (Some(viz), Some(recording)) = (&options.viz, recording.into_inner()) {
+        export_recording(viz, &recording, part);
+    }
+
+    result.map(|result| result.to_string())
+}
+
+fn export_recording(viz: &VizOptions, recording: &viz::Recording, part: u8) {
+    if recording.frames.is_empty() {
+        println!("  No frames recorded, see `viz::frame`.");
+        return;
+    }
+    match viz.export(recording, part) {
+        Ok(path) => println!(
+            "  Recorded {} of {} frames to \"{}\".",
+            recording.frames.len(),
+            recording.offered,
+            path.display()
+        ),
+        Err(e) => eprintln!("  Failed to write the visualization: {e}"),
     }
 }
 
+/// Benches the day's `parse` function on the real input and prints it as the parse time of the day.
+pub fn time_parse<'a, T>(parse: impl Fn(&'a str) -> T, input: &'a str) {
+    let (_, duration, samples) = run_timed(parse, input, true, |_| {});
+    print!("\r");
+    println!("Parse:{}", format_duration(&duration, samples));
+}
+
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///  2. in release, the function is benched (approx. `bench.target_ms` of execution time or `bench.min_samples` samples, whatever take longer.)
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    is_timed: bool,
     hook: impl Fn(&T),
 ) -> (T, Duration, u128) {
     let timer = Instant::now();
@@ -41,7 +207,7 @@ fn run_timed<I: Copy, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let run = if is_timed {
         bench(func, input, &base_time)
     } else {
         (base_time, 1)
@@ -56,8 +222,20 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    sample(func, input, base_time)
+}
+
+/// Runs `func` as often as `bench` in `aoc.toml` asks for, given the time of a first run.
+/// Returns the average duration and the number of samples.
+pub(crate) fn sample<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+) -> (Duration, u128) {
+    let bench_config = &config::get().bench;
+    let bench_iterations = (Duration::from_millis(bench_config.target_ms).as_nanos()
+        / cmp::max(base_time.as_nanos(), 10))
+    .clamp(bench_config.min_samples, bench_config.max_samples);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -125,41 +303,129 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+/// Submits answers via aoc-cli and records every step in the answer ledger.
+/// With [`Submit::Auto`], part two is only submitted once part one has been accepted.
+pub fn submit_answers(day: Day, answers: &[(u8, Option<String>)], submit: Submit) -> Status {
+    // single-part days only run their first part, so `auto` submits just that.
+    let parts: Vec<u8> = match submit {
+        Submit::Part(part) => vec![part],
+        Submit::Auto => answers.iter().map(|(part, _)| *part).collect(),
+    };
 
-    if !args.contains(&"--submit".into()) {
-        return None;
+    if config::get().submit.policy == SubmitPolicy::Deny {
+        eprintln!("Submitting is disabled by `submit.policy` in aoc.toml.");
+        return Status::Failure;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        return Status::Failure;
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
-
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    // storing the ledger after submitting would wipe the answers that could not be read.
+    let mut ledger = match Ledger::read_from_file() {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("Refusing to submit, {e}. Fix or remove the file and run again.");
+            return Status::Failure;
+        }
     };
 
-    if part_submit != part {
+    for part in parts {
+        let Some(answer) = answers
+            .iter()
+            .find(|(p, _)| *p == part)
+            .and_then(|(_, answer)| answer.as_deref())
+        else {
+            eprintln!("Part {part} has no answer to submit.");
+            return Status::Failure;
+        };
+
+        match submit_part(day, part, answer, &mut ledger) {
+            Some(verdict) if verdict.is_accepted() => {}
+            Some(Verdict::Incorrect) => return Status::WrongAnswer,
+            Some(_) => return Status::Failure,
+            // skipped on the user's request.
+            None => return Status::Success,
+        }
+    }
+
+    Status::Success
+}
+
+/// Submits a single answer, waiting out cooldowns. Returns `None` if the user declined to submit.
+fn submit_part(day: Day, part: u8, answer: &str, ledger: &mut Ledger) -> Option<Verdict> {
+    match ledger.verdict_for(day, part, answer) {
+        Some(Verdict::Correct) => {
+            println!("Part {part}: \"{answer}\" was accepted before, skipping submission.");
+            return Some(Verdict::Correct);
+        }
+        Some(Verdict::Incorrect) => {
+            println!("Part {part}: \"{answer}\" was rejected before, skipping submission.");
+            return Some(Verdict::Incorrect);
+        }
+        _ => {}
+    }
+
+    if config::get().submit.policy == SubmitPolicy::Confirm
+        && !confirm(&format!(
+            "Submit \"{answer}\" as the answer to part {part}?"
+        ))
+    {
+        println!("Skipped submission.");
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    for _ in 0..MAX_SUBMIT_ATTEMPTS {
+        println!("Submitting part {part} via aoc-cli...");
+
+        // the verdict is printed either way, aoc-cli exits with an error for some responses.
+        let verdict = match aoc_cli::submit(day, part, answer) {
+            Ok(output) | Err(AocCommandError::BadExitStatus(output)) => {
+                Verdict::parse(&String::from_utf8_lossy(&output.stdout))
+            }
+            Err(e) => {
+                eprintln!("failed to call aoc-cli: {e}");
+                return Some(Verdict::Unknown);
+            }
+        };
+
+        ledger.record(day, part, answer, verdict);
+        if let Err(e) = ledger.store_file() {
+            eprintln!("Failed to store answer ledger: {e}");
+        }
+
+        println!("Part {part}: {verdict}.");
+
+        match verdict {
+            Verdict::Correct => stats::track(day, Milestone::Accepted(part)),
+            Verdict::Incorrect => stats::track(day, Milestone::Rejected(part)),
+            _ => {}
+        }
+
+        match verdict {
+            Verdict::TooRecent(wait) => {
+                let wait = wait + Duration::from_secs(1);
+                println!("Waiting {}s before retrying...", wait.as_secs());
+                thread::sleep(wait);
+            }
+            verdict => return Some(verdict),
+        }
+    }
+
+    eprintln!("Giving up after {MAX_SUBMIT_ATTEMPTS} attempts.");
+    Some(Verdict::Unknown)
+}
+
+/// Asks a yes / no question on the terminal, defaulting to "no".
+fn confirm(question: &str) -> bool {
+    print!("{question} [y/N] ");
+    let _ = stdout().flush();
+
+    let mut answer = String::new();
+    if stdin().read_line(&mut answer).is_err() {
+        return false;
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    matches!(answer.trim(), "y" | "Y" | "yes")
 }
@@ -0,0 +1,305 @@
+/// Complexity analysis for `cargo scale`: runs a part on inputs of increasing size and fits the
+/// exponent `k` of `time ≈ c · sizeᵏ`, e.g. `k ≈ 1` for a linear and `k ≈ 2` for a quadratic solution.
+/// Inputs come from the day's generator hook or are cut from the real input at record boundaries.
+use std::{cell::Cell, fmt::Display, fmt::Write, time::Duration, time::Instant};
+
+use crate::template::fuzz::{self, Panic, Rng};
+use crate::template::input::{self, Options, Shape};
+use crate::template::runner::sample;
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+/// Flags passed to a solution binary by `cargo scale`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScaleOptions {
+    /// Number of input sizes, each half the size of the next.
+    pub steps: u32,
+    /// Size of the largest input in records. Defaults to the size of the real input.
+    pub max: Option<usize>,
+    /// Only analyze this part.
+    pub part: Option<u8>,
+}
+
+impl Default for ScaleOptions {
+    fn default() -> Self {
+        Self {
+            steps: 6,
+            max: None,
+            part: None,
+        }
+    }
+}
+
+/// End offsets of the records in `input` that truncation may cut after.
+/// - lines for most shapes, and lines of the last section for [`Shape::Sections`].
+/// - blocks separated by a blank line for [`Shape::Blocks`].
+/// - space or comma separated values for a [`Shape::SingleLine`] like `125 17`, characters otherwise.
+fn record_ends(input: &str, shape: Shape) -> Vec<usize> {
+    let content = input.trim_end_matches('\n');
+
+    let line_ends = |start: usize| {
+        let mut ends = vec![];
+        let mut offset = start;
+        for line in content[start..].split('\n') {
+            offset += line.len();
+            ends.push(offset);
+            offset += 1;
+        }
+        ends
+    };
+
+    match shape {
+        Shape::SingleLine if content.contains([' ', ',']) => content
+            .match_indices([' ', ','])
+            .map(|(i, _)| i)
+            .chain([content.len()])
+            .filter(|&end| end > 0 && !content[..end].ends_with([' ', ',']))
+            .collect(),
+        Shape::SingleLine => content
+            .char_indices()
+            .map(|(i, c)| i + c.len_utf8())
+            .collect(),
+        Shape::Sections(_) => line_ends(content.rfind("\n\n").map_or(0, |i| i + 2)),
+        Shape::Blocks => content
+            .match_indices("\n\n")
+            .map(|(i, _)| i)
+            .chain([content.len()])
+            .collect(),
+        Shape::Any | Shape::Lines | Shape::Grid => line_ends(0),
+    }
+}
+
+/// Number of records in `input`, see [`truncate`].
+pub fn record_count(input: &str, shape: Shape) -> usize {
+    record_ends(input, shape).len()
+}
+
+/// Keeps the first `size` records of `input`, keeping a trailing newline if `input` has one.
+pub fn truncate(input: &str, shape: Shape, size: usize) -> String {
+    let ends = record_ends(input, shape);
+    let Some(&end) = ends.get(size.clamp(1, ends.len().max(1)) - 1) else {
+        return input.to_string();
+    };
+
+    let mut truncated = input[..end].to_string();
+    if input.ends_with('\n') {
+        truncated.push('\n');
+    }
+    truncated
+}
+
+/// `steps` sizes up to `max`, each half the size of the next.
+pub fn sizes(max: usize, steps: u32) -> Vec<usize> {
+    let mut sizes: Vec<usize> = (0..steps)
+        .rev()
+        .filter_map(|i| max.checked_shr(i))
+        .filter(|&size| size > 0)
+        .collect();
+    sizes.dedup();
+    sizes
+}
+
+/// Least-squares fit of `log(time) = k · log(size) + c`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fit {
+    pub exponent: f64,
+    /// How well the power law explains the timings, 1 is a perfect fit.
+    pub r_squared: f64,
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub fn fit(points: &[(usize, Duration)]) -> Option<Fit> {
+    let points: Vec<(f64, f64)> = points
+        .iter()
+        .filter(|(size, time)| *size > 0 && !time.is_zero())
+        .map(|(size, time)| ((*size as f64).ln(), (time.as_nanos() as f64).ln()))
+        .collect();
+
+    if points.len() < 2 {
+        return None;
+    }
+
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+
+    let ss_xx: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+    let ss_xy: f64 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+    let ss_yy: f64 = points.iter().map(|p| (p.1 - mean_y).powi(2)).sum();
+
+    if ss_xx == 0.0 {
+        return None;
+    }
+
+    let exponent = ss_xy / ss_xx;
+    let r_squared = if ss_yy == 0.0 {
+        1.0
+    } else {
+        (ss_xy * ss_xy) / (ss_xx * ss_yy)
+    };
+
+    Some(Fit {
+        exponent,
+        r_squared,
+    })
+}
+
+/// Renders measured sizes with their time and the growth over the previous size.
+pub fn render_table(rows: &[(usize, Option<Duration>)]) -> String {
+    let mut out = format!("  {:>10}  {:>10}  {:>7}\n", "Size", "Time", "Growth");
+    let mut previous: Option<Duration> = None;
+
+    for (size, time) in rows {
+        let growth = match (previous, time) {
+            (Some(prev), Some(time)) if !prev.is_zero() => {
+                format!("×{:.2}", time.as_secs_f64() / prev.as_secs_f64())
+            }
+            _ => String::new(),
+        };
+        let time_str = time.map_or("✖".into(), |t| format!("{t:.1?}"));
+        let _ = writeln!(out, "  {size:>10}  {time_str:>10}  {growth:>7}");
+        previous = *time;
+    }
+
+    out
+}
+
+/// Times `func` on inputs of increasing size and prints the table and the fitted exponent.
+/// Sizes where the part has no answer or panics are shown as failed and left out of the fit.
+pub fn run_part<T: Display>(
+    func: impl Fn(&str) -> Option<T>,
+    input: &str,
+    part: u8,
+    input_options: &Options,
+    options: &ScaleOptions,
+) {
+    let max = options
+        .max
+        .unwrap_or_else(|| record_count(input, input_options.shape));
+    let source = if input_options.generator.is_some() {
+        "generator"
+    } else {
+        "truncated input"
+    };
+
+    println!("{ANSI_BOLD}Part {part}{ANSI_RESET} ({source})");
+
+    let mut panics: Vec<(usize, Panic)> = vec![];
+    let rows: Vec<(usize, Option<Duration>)> = fuzz::record_panics(|| {
+        sizes(max, options.steps)
+            .into_iter()
+            .map(|size| {
+                let scaled = match input_options.generator {
+                    Some(generate) => {
+                        input::normalize(&generate(&mut Rng::new(0), size), input_options.trim).0
+                    }
+                    None => truncate(input, input_options.shape, size),
+                };
+
+                // a cut input may be invalid for the part, which should not end the analysis.
+                let answered = Cell::new(false);
+                let timer = Instant::now();
+                if let Some(panic) =
+                    fuzz::catch(&|input| answered.set(func(input).is_some()), &scaled)
+                {
+                    panics.push((size, panic));
+                    return (size, None);
+                }
+                let base_time = timer.elapsed();
+
+                let time = answered
+                    .get()
+                    .then(|| sample(&func, scaled.as_str(), &base_time).0);
+                (size, time)
+            })
+            .collect()
+    });
+
+    print!("{}", render_table(&rows));
+    for (size, panic) in &panics {
+        println!(
+            "  Size {size} panicked at {}: {}",
+            panic.location, panic.message
+        );
+    }
+
+    let points: Vec<(usize, Duration)> = rows
+        .iter()
+        .filter_map(|(size, time)| Some((*size, (*time)?)))
+        .collect();
+
+    match fit(&points) {
+        Some(fit) => println!(
+            "  time ∝ size^{:.2} (R² = {:.3})",
+            fit.exponent, fit.r_squared
+        ),
+        None => println!("  Not enough sizes with an answer to fit an exponent."),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{fit, record_count, render_table, sizes, truncate};
+    use crate::template::Shape;
+
+    #[test]
+    fn truncates_at_record_boundaries() {
+        assert_eq!(truncate("a\nb\nc\n", Shape::Lines, 2), "a\nb\n");
+        assert_eq!(truncate("ab\ncd", Shape::Grid, 1), "ab");
+        assert_eq!(truncate("125 17 6\n", Shape::SingleLine, 2), "125 17\n");
+        assert_eq!(
+            truncate("2333133121414131402", Shape::SingleLine, 4),
+            "2333"
+        );
+        assert_eq!(
+            truncate("r|1\nr|2\n\n1,2\n3,4\n5,6\n", Shape::Sections(2), 2),
+            "r|1\nr|2\n\n1,2\n3,4\n"
+        );
+        assert_eq!(
+            truncate("a 1\nb 2\n\na 3\nb 4\n\na 5\n", Shape::Blocks, 2),
+            "a 1\nb 2\n\na 3\nb 4\n"
+        );
+        assert_eq!(truncate("a\nb\n", Shape::Lines, 10), "a\nb\n");
+    }
+
+    #[test]
+    fn counts_records() {
+        assert_eq!(record_count("a\nb\nc\n", Shape::Any), 3);
+        assert_eq!(record_count("1, 2, 3\n", Shape::SingleLine), 3);
+        assert_eq!(record_count("r|1\n\n1,2\n3,4", Shape::Sections(2)), 2);
+        assert_eq!(record_count("a\nb\n\nc\n\nd\ne\n", Shape::Blocks), 3);
+    }
+
+    #[test]
+    fn halves_sizes() {
+        assert_eq!(sizes(100, 4), [12, 25, 50, 100]);
+        assert_eq!(sizes(3, 6), [1, 3]);
+    }
+
+    #[test]
+    fn fits_power_laws() {
+        let quadratic: Vec<(usize, Duration)> = [10, 20, 40, 80]
+            .into_iter()
+            .map(|n| (n, Duration::from_nanos((n * n) as u64 * 3)))
+            .collect();
+        let fit = fit(&quadratic).unwrap();
+        assert!((fit.exponent - 2.0).abs() < 1e-9);
+        assert!((fit.r_squared - 1.0).abs() < 1e-9);
+
+        assert_eq!(super::fit(&quadratic[..1]), None);
+    }
+
+    #[test]
+    fn renders_growth() {
+        let table = render_table(&[
+            (10, Some(Duration::from_micros(1))),
+            (20, Some(Duration::from_micros(4))),
+            (40, None),
+        ]);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], "        Size        Time   Growth");
+        assert_eq!(lines[2], "          20       4.0µs    ×4.00");
+        assert_eq!(lines[3], "          40           ✖         ");
+    }
+}
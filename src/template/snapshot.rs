@@ -0,0 +1,301 @@
+/// Snapshot tests for multi-line outputs like rendered grids, which are tedious to write as
+/// string literals. The expected text is stored in `data/snapshots/{day}-{name}.txt`.
+///
+/// ```ignore
+/// assert_snapshot(DAY, "final-map", &render(&map));
+/// ```
+///
+/// A missing or outdated snapshot fails the test with a diff. `cargo snapshots --update-snapshots`
+/// runs the tests with [`UPDATE_ENV`] set, which writes the actual outputs instead.
+use std::fmt::{Display, Write};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::template::{config, input, Day};
+
+/// Environment variable that makes snapshot assertions write their outputs instead of comparing.
+pub const UPDATE_ENV: &str = "UPDATE_SNAPSHOTS";
+
+/// Unchanged lines shown around each change in a diff.
+const CONTEXT: usize = 2;
+
+/// Path of the snapshot `name` of `day`.
+pub fn snapshot_path(day: Day, name: &str) -> PathBuf {
+    config::get()
+        .data_dir
+        .join("snapshots")
+        .join(format!("{day}-{name}.txt"))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Matched,
+    Written,
+}
+
+/// An output that does not match its snapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub path: PathBuf,
+    /// Contents of the snapshot, `None` if it does not exist.
+    pub expected: Option<String>,
+    pub actual: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.expected {
+            None => write!(
+                f,
+                "snapshot \"{}\" does not exist. Create it with `cargo snapshots --update-snapshots`.\nActual output:\n{}",
+                self.path.display(),
+                self.actual
+            ),
+            Some(expected) => write!(
+                f,
+                "output does not match snapshot \"{}\" (- snapshot, + actual). Accept it with `cargo snapshots --update-snapshots`.\n{}",
+                self.path.display(),
+                diff(expected, &self.actual)
+            ),
+        }
+    }
+}
+
+/// Compares `actual` to the snapshot at `path`, or writes it there if `update` is set.
+/// Line endings are normalized and trailing newlines ignored, like for puzzle inputs.
+///
+/// # Panics
+/// If the snapshot can't be read or written.
+pub fn check(path: &Path, actual: &str, update: bool) -> Result<Outcome, Mismatch> {
+    let (actual, _) = input::normalize(actual, false);
+    let actual = actual.trim_end_matches('\n');
+
+    if update {
+        if let Some(folder) = path.parent() {
+            fs::create_dir_all(folder)
+                .unwrap_or_else(|e| panic!("Failed to create \"{}\": {e}", folder.display()));
+        }
+        fs::write(path, format!("{actual}\n"))
+            .unwrap_or_else(|e| panic!("Failed to write \"{}\": {e}", path.display()));
+        return Ok(Outcome::Written);
+    }
+
+    let expected = match fs::read_to_string(path) {
+        Ok(contents) => input::normalize(&contents, false).0,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Err(Mismatch {
+                path: path.to_path_buf(),
+                expected: None,
+                actual: actual.to_string(),
+            })
+        }
+        Err(e) => panic!("Failed to read \"{}\": {e}", path.display()),
+    };
+
+    if expected.trim_end_matches('\n') == actual {
+        Ok(Outcome::Matched)
+    } else {
+        Err(Mismatch {
+            path: path.to_path_buf(),
+            expected: Some(expected.trim_end_matches('\n').to_string()),
+            actual: actual.to_string(),
+        })
+    }
+}
+
+/// Asserts that `actual` matches the snapshot `name` of `day`, see the module documentation.
+///
+/// # Panics
+/// If the output does not match, with a diff against the snapshot.
+#[track_caller]
+pub fn assert_snapshot(day: Day, name: &str, actual: &str) {
+    let update = std::env::var_os(UPDATE_ENV).is_some();
+    if let Err(mismatch) = check(&snapshot_path(day, name), actual, update) {
+        panic!("{mismatch}");
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Same(usize, usize),
+    Removed(usize),
+    Added(usize),
+}
+
+/// Line operations that turn `a` into `b`, from a longest common subsequence.
+fn line_ops(a: &[&str], b: &[&str]) -> Vec<Op> {
+    // lengths[i][j] is the length of the longest common subsequence of a[i..] and b[j..].
+    let mut lengths = vec![vec![0u32; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lengths[i][j] = if a[i] == b[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut ops = vec![];
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            ops.push(Op::Same(i, j));
+            i += 1;
+            j += 1;
+        } else if j == b.len() || (i < a.len() && lengths[i + 1][j] >= lengths[i][j + 1]) {
+            ops.push(Op::Removed(i));
+            i += 1;
+        } else {
+            ops.push(Op::Added(j));
+            j += 1;
+        }
+    }
+    ops
+}
+
+/// Marks the characters that differ between two lines of the same length, `None` otherwise.
+fn markers(removed: &str, added: &str) -> Option<String> {
+    if removed.chars().count() != added.chars().count() {
+        return None;
+    }
+    let marked: String = removed
+        .chars()
+        .zip(added.chars())
+        .map(|(r, a)| if r == a { ' ' } else { '^' })
+        .collect();
+    Some(marked.trim_end().to_string())
+}
+
+/// A line diff of `expected` and `actual` with line numbers and a few unchanged lines of context.
+/// A changed line is shown as a `-` and `+` pair, with `^` under the characters that differ if
+/// the line kept its length, which makes single changed cells of a grid easy to spot.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let a: Vec<&str> = expected.lines().collect();
+    let b: Vec<&str> = actual.lines().collect();
+    let ops = line_ops(&a, &b);
+
+    let near_change = |k: usize| {
+        ops[k.saturating_sub(CONTEXT)..(k + CONTEXT + 1).min(ops.len())]
+            .iter()
+            .any(|op| !matches!(op, Op::Same(..)))
+    };
+
+    let mut out = String::new();
+    let mut skipped = false;
+    let mut k = 0;
+    while k < ops.len() {
+        match ops[k] {
+            Op::Same(i, _) => {
+                if near_change(k) {
+                    let _ = writeln!(out, "  {:>4} | {}", i + 1, a[i]);
+                    skipped = false;
+                } else if !skipped {
+                    let _ = writeln!(out, "  {:>4} | ...", "");
+                    skipped = true;
+                }
+                k += 1;
+            }
+            Op::Removed(_) | Op::Added(_) => {
+                let end = ops[k..]
+                    .iter()
+                    .position(|op| matches!(op, Op::Same(..)))
+                    .map_or(ops.len(), |n| k + n);
+                let removed: Vec<usize> = ops[k..end]
+                    .iter()
+                    .filter_map(|op| match op {
+                        Op::Removed(i) => Some(*i),
+                        _ => None,
+                    })
+                    .collect();
+                let added: Vec<usize> = ops[k..end]
+                    .iter()
+                    .filter_map(|op| match op {
+                        Op::Added(j) => Some(*j),
+                        _ => None,
+                    })
+                    .collect();
+
+                if removed.len() == added.len() {
+                    for (&i, &j) in removed.iter().zip(&added) {
+                        let _ = writeln!(out, "- {:>4} | {}", i + 1, a[i]);
+                        let _ = writeln!(out, "+ {:>4} | {}", j + 1, b[j]);
+                        if let Some(marked) = markers(a[i], b[j]) {
+                            let _ = writeln!(out, "  {:>4} | {marked}", "");
+                        }
+                    }
+                } else {
+                    for &i in &removed {
+                        let _ = writeln!(out, "- {:>4} | {}", i + 1, a[i]);
+                    }
+                    for &j in &added {
+                        let _ = writeln!(out, "+ {:>4} | {}", j + 1, b[j]);
+                    }
+                }
+                skipped = false;
+                k = end;
+            }
+        }
+    }
+    out
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, diff, Outcome};
+
+    #[test]
+    fn marks_changed_cells() {
+        let expected = "#..\n...\n.#.\n...\n...\n...\n..#\n";
+        let actual = "#..\n...\n..#\n...\n...\n...\n..#\n";
+        assert_eq!(
+            diff(expected, actual),
+            [
+                "     1 | #..",
+                "     2 | ...",
+                "-    3 | .#.",
+                "+    3 | ..#",
+                "       |  ^^",
+                "     4 | ...",
+                "     5 | ...",
+                "       | ...",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn shows_added_and_removed_lines() {
+        assert_eq!(
+            diff("a\nb\nc", "a\nc\nd\ne"),
+            [
+                "     1 | a",
+                "-    2 | b",
+                "     3 | c",
+                "+    3 | d",
+                "+    4 | e",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn writes_and_compares_snapshots() {
+        let path = std::env::temp_dir().join(format!("aoc-snapshot-{}.txt", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let missing = check(&path, "ab\ncd\n", false).unwrap_err();
+        assert_eq!(missing.expected, None);
+        assert!(missing.to_string().contains("does not exist"));
+
+        assert_eq!(check(&path, "ab\r\ncd", true), Ok(Outcome::Written));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "ab\ncd\n");
+        assert_eq!(check(&path, "ab\ncd\n\n", false), Ok(Outcome::Matched));
+
+        let mismatch = check(&path, "ab\nce", false).unwrap_err();
+        assert!(mismatch.to_string().contains("+    2 | ce"));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,221 @@
+/// Hierarchical timing of phases inside a solution.
+/// Wrap a phase with [`span!`](crate::span) or [`time_block!`](crate::time_block) and the runner prints
+/// a tree of the cumulative time and call count of every span after each part.
+/// Spans only record with the `spans` feature enabled, e.g. via `cargo solve <day> --spans`.
+/// Otherwise, they compile to nothing.
+use std::{fmt::Write, time::Duration};
+
+#[cfg(feature = "spans")]
+use std::{cell::RefCell, time::Instant};
+
+/// Cumulative time and calls of a span, keyed by its name and parent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub name: &'static str,
+    pub parent: Option<usize>,
+    pub total: Duration,
+    pub calls: u64,
+}
+
+/// Spans recorded on one thread, in the order they were first entered.
+#[derive(Clone, Debug, Default)]
+pub struct Tree {
+    pub spans: Vec<Span>,
+    /// Spans that are currently entered, innermost last.
+    stack: Vec<usize>,
+}
+
+impl Tree {
+    /// Enters `name` as a child of the innermost open span.
+    pub fn enter(&mut self, name: &'static str) {
+        let parent = self.stack.last().copied();
+        let index = self
+            .spans
+            .iter()
+            .position(|s| s.parent == parent && s.name == name)
+            .unwrap_or_else(|| {
+                self.spans.push(Span {
+                    name,
+                    parent,
+                    total: Duration::ZERO,
+                    calls: 0,
+                });
+                self.spans.len() - 1
+            });
+        self.stack.push(index);
+    }
+
+    /// Leaves the innermost open span, adding `elapsed` to its total.
+    pub fn exit(&mut self, elapsed: Duration) {
+        if let Some(index) = self.stack.pop() {
+            let span = &mut self.spans[index];
+            span.total += elapsed;
+            span.calls += 1;
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Renders the spans as an indented tree, children below their parent.
+    pub fn render(&self) -> String {
+        let width = self.width(None, 0);
+        let mut out = String::new();
+        self.render_children(&mut out, None, 0, width);
+        out
+    }
+
+    fn children(&self, parent: Option<usize>) -> impl Iterator<Item = (usize, &Span)> {
+        self.spans
+            .iter()
+            .enumerate()
+            .filter(move |(_, s)| s.parent == parent)
+    }
+
+    /// Width of the widest indented name below `parent`, to align the timings.
+    fn width(&self, parent: Option<usize>, depth: usize) -> usize {
+        self.children(parent)
+            .map(|(i, s)| (depth * 2 + s.name.len()).max(self.width(Some(i), depth + 1)))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn render_children(&self, out: &mut String, parent: Option<usize>, depth: usize, width: usize) {
+        for (index, span) in self.children(parent) {
+            let name = format!("{}{}", "  ".repeat(depth), span.name);
+            let _ = writeln!(
+                out,
+                "  {name:<width$}  {:>10}  {} call{}",
+                format!("{:.1?}", span.total),
+                span.calls,
+                if span.calls == 1 { "" } else { "s" }
+            );
+            self.render_children(out, Some(index), depth + 1, width);
+        }
+    }
+}
+
+#[cfg(feature = "spans")]
+thread_local! {
+    static TREE: RefCell<Tree> = RefCell::new(Tree::default());
+}
+
+/// Closes its span when dropped. Bind it to a named variable, `_` drops it right away.
+#[must_use = "the span is closed when the guard is dropped"]
+pub struct Guard {
+    #[cfg(feature = "spans")]
+    start: Instant,
+}
+
+#[cfg(feature = "spans")]
+impl Drop for Guard {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        TREE.with(|tree| tree.borrow_mut().exit(elapsed));
+    }
+}
+
+/// Opens a span on the current thread, see [`span!`](crate::span).
+#[inline(always)]
+pub fn enter(name: &'static str) -> Guard {
+    #[cfg(feature = "spans")]
+    {
+        TREE.with(|tree| tree.borrow_mut().enter(name));
+        Guard {
+            start: Instant::now(),
+        }
+    }
+
+    #[cfg(not(feature = "spans"))]
+    {
+        let _ = name;
+        Guard {}
+    }
+}
+
+/// Takes the spans recorded on the current thread so far, leaving an empty tree.
+/// Always empty without the `spans` feature.
+pub fn take() -> Tree {
+    #[cfg(feature = "spans")]
+    {
+        TREE.with(|tree| std::mem::take(&mut *tree.borrow_mut()))
+    }
+
+    #[cfg(not(feature = "spans"))]
+    {
+        Tree::default()
+    }
+}
+
+/// Opens a span that lasts until the end of the enclosing scope.
+///
+/// ```ignore
+/// let _span = advent_of_code::span!("compressed_size");
+/// ```
+#[macro_export]
+macro_rules! span {
+    ($name:expr) => {
+        $crate::template::spans::enter($name)
+    };
+}
+
+/// Evaluates a block inside a span and returns its value.
+///
+/// ```ignore
+/// let rendered = advent_of_code::time_block!("render", { render_grid(&pos, 101, 103) });
+/// ```
+#[macro_export]
+macro_rules! time_block {
+    ($name:expr, $body:block) => {{
+        let _span = $crate::span!($name);
+        $body
+    }};
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::Tree;
+
+    #[test]
+    fn accumulates_spans_per_parent() {
+        let mut tree = Tree::default();
+        tree.enter("search");
+        for _ in 0..3 {
+            tree.enter("render");
+            tree.exit(Duration::from_micros(10));
+            tree.enter("compress");
+            tree.exit(Duration::from_micros(100));
+        }
+        tree.exit(Duration::from_millis(1));
+        tree.enter("render");
+        tree.exit(Duration::from_micros(5));
+
+        assert_eq!(tree.spans.len(), 4);
+        assert_eq!(tree.spans[1].calls, 3);
+        assert_eq!(tree.spans[2].total, Duration::from_micros(300));
+        assert_eq!(tree.spans[3].parent, None);
+
+        let rendered = tree.render();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "  search           1.0ms  1 call",
+                "    render        30.0µs  3 calls",
+                "    compress     300.0µs  3 calls",
+                "  render           5.0µs  1 call",
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_unbalanced_exits() {
+        let mut tree = Tree::default();
+        tree.exit(Duration::from_micros(1));
+        assert!(tree.is_empty());
+        assert_eq!(tree.render(), "");
+    }
+}
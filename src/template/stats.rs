@@ -0,0 +1,446 @@
+/// Personal statistics: a timeline of when each puzzle was started and solved.
+/// Milestones are recorded by `scaffold`, `download`, `solve` and `--submit` in `data/stats.json`,
+/// together with the year of the event they belong to.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::event::{format_hms, format_server_time, Event};
+use crate::template::{config, Day};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Milestone {
+    Scaffolded,
+    Downloaded,
+    FirstRun,
+    /// A wrong answer was submitted for a part.
+    Rejected(u8),
+    /// An answer was accepted, i.e. a star was earned.
+    Accepted(u8),
+}
+
+impl Milestone {
+    fn key(self) -> &'static str {
+        match self {
+            Milestone::Scaffolded => "scaffolded",
+            Milestone::Downloaded => "downloaded",
+            Milestone::FirstRun => "first_run",
+            Milestone::Rejected(_) => "rejected",
+            Milestone::Accepted(_) => "accepted",
+        }
+    }
+
+    fn part(self) -> Option<u8> {
+        match self {
+            Milestone::Rejected(part) | Milestone::Accepted(part) => Some(part),
+            _ => None,
+        }
+    }
+
+    /// Whether the milestone is only recorded the first time it happens.
+    fn is_once(self) -> bool {
+        !matches!(self, Milestone::Rejected(_))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub year: u16,
+    pub day: Day,
+    pub milestone: Milestone,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+/// All recorded milestones, in the order they happened.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Stats {
+    pub data: Vec<Record>,
+}
+
+impl Stats {
+    /// Dehydrate stats to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(config::get().stats_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate stats from a JSON file. If not present, returns empty stats.
+    /// Fails if the file can't be read or parsed, as storing empty stats would wipe it.
+    pub fn read_from_file() -> Result<Self, String> {
+        let path = config::get().stats_path();
+        match fs::read_to_string(&path) {
+            Ok(json) => Stats::try_from(json),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Stats::default()),
+            Err(e) => Err(e.to_string()),
+        }
+        .map_err(|e| {
+            let e = e.trim_end_matches('.');
+            format!("failed to read \"{}\": {e}", path.display())
+        })
+    }
+
+    /// Records a milestone of the `year` event at `timestamp`. Returns `false` if it was recorded before.
+    pub fn record(&mut self, year: u16, day: Day, milestone: Milestone, timestamp: u64) -> bool {
+        if milestone.is_once()
+            && self
+                .data
+                .iter()
+                .any(|r| r.year == year && r.day == day && r.milestone == milestone)
+        {
+            return false;
+        }
+        self.data.push(Record {
+            year,
+            day,
+            milestone,
+            timestamp,
+        });
+        true
+    }
+
+    /// Records of the `year` event only, as days of different events share their numbers.
+    pub fn for_year(&self, year: u16) -> Self {
+        Stats {
+            data: self
+                .data
+                .iter()
+                .filter(|r| r.year == year)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Timestamp of the first time a milestone was reached on `day`.
+    pub fn first(&self, day: Day, milestone: Milestone) -> Option<u64> {
+        self.data
+            .iter()
+            .filter(|r| r.day == day && r.milestone == milestone)
+            .map(|r| r.timestamp)
+            .min()
+    }
+
+    /// When work on `day` started: the earliest of scaffolding, downloading or running it.
+    pub fn started(&self, day: Day) -> Option<u64> {
+        [
+            Milestone::Scaffolded,
+            Milestone::Downloaded,
+            Milestone::FirstRun,
+        ]
+        .into_iter()
+        .filter_map(|milestone| self.first(day, milestone))
+        .min()
+    }
+
+    pub fn wrong_attempts(&self, day: Day) -> usize {
+        self.data
+            .iter()
+            .filter(|r| r.day == day && matches!(r.milestone, Milestone::Rejected(_)))
+            .count()
+    }
+
+    /// Whether every part of `day` was solved on the day its puzzle unlocked.
+    pub fn is_solved_on_time(&self, day: Day, event: &Event) -> bool {
+        let Some(unlock) = event.unlock_time(day).and_then(unix_seconds) else {
+            return false;
+        };
+        (1..=event.parts(day)).all(|part| {
+            self.first(day, Milestone::Accepted(part))
+                .is_some_and(|solved| solved >= unlock && solved - unlock < 86_400)
+        })
+    }
+}
+
+/// Records a milestone for `day` of the current event in the stats file, reporting failures without aborting.
+/// A stats file that can't be read is left untouched.
+pub fn track(day: Day, milestone: Milestone) {
+    let Some(now) = unix_seconds(SystemTime::now()) else {
+        return;
+    };
+
+    let mut stats = match Stats::read_from_file() {
+        Ok(stats) => stats,
+        Err(e) => {
+            eprintln!("Not recording stats, {e}. Fix or remove the file to record them again.");
+            return;
+        }
+    };
+    let year = config::get().current_event().year;
+    if stats.record(year, day, milestone, now) {
+        if let Err(e) = stats.store_file() {
+            eprintln!("Failed to store stats: {e}");
+        }
+    }
+}
+
+fn unix_seconds(time: SystemTime) -> Option<u64> {
+    time.duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Statistics of a single day, as shown by `cargo stats`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayReport {
+    pub day: Day,
+    pub started: Option<u64>,
+    pub first_star: Option<Duration>,
+    pub second_star: Option<Duration>,
+    pub wrong_attempts: usize,
+    /// Number of consecutive days up to this one that were solved on the day they unlocked.
+    pub streak: usize,
+}
+
+pub fn report(stats: &Stats, event: &Event) -> Vec<DayReport> {
+    let stats = &stats.for_year(event.year);
+    let mut streak = 0;
+
+    event
+        .days()
+        .map(|day| {
+            streak = if stats.is_solved_on_time(day, event) {
+                streak + 1
+            } else {
+                0
+            };
+
+            let started = stats.started(day);
+            let time_to = |part| {
+                let solved = stats.first(day, Milestone::Accepted(part))?;
+                Some(Duration::from_secs(solved.saturating_sub(started?)))
+            };
+
+            DayReport {
+                day,
+                started,
+                first_star: time_to(1),
+                second_star: time_to(2),
+                wrong_attempts: stats.wrong_attempts(day),
+                streak,
+            }
+        })
+        .filter(|report| report.started.is_some() || report.first_star.is_some())
+        .collect()
+}
+
+impl Display for DayReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let star = |time: Option<Duration>| time.map_or_else(|| "-".into(), format_hms);
+        write!(
+            f,
+            "{:>3}  {:<16}  {:>11}  {:>11}  {:>5}  {:>6}",
+            self.day.to_string(),
+            self.started.map_or_else(|| "-".into(), format_server_time),
+            star(self.first_star),
+            star(self.second_star),
+            self.wrong_attempts,
+            self.streak
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Stats> for JsonValue {
+    fn from(value: Stats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Stats {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Stats {
+            data: json_data
+                .iter()
+                .map(Record::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Record> for JsonValue {
+    fn from(value: &Record) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("year".into(), JsonValue::Number(f64::from(value.year)));
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "milestone".into(),
+            JsonValue::String(value.milestone.key().into()),
+        );
+        if let Some(part) = value.milestone.part() {
+            map.insert("part".into(), JsonValue::Number(f64::from(part)));
+        }
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Record {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let number = |key: &str| json.get(key).and_then(|v| v.get::<f64>().copied());
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected record.day to be a Day struct.")?;
+
+        let part = || {
+            number("part")
+                .filter(|part| *part == 1.0 || *part == 2.0)
+                .map(|part| part as u8)
+                .ok_or("Expected record.part to be 1 or 2.")
+        };
+
+        let milestone = match json.get("milestone").and_then(|v| v.get::<String>()) {
+            Some(m) if m == "scaffolded" => Milestone::Scaffolded,
+            Some(m) if m == "downloaded" => Milestone::Downloaded,
+            Some(m) if m == "first_run" => Milestone::FirstRun,
+            Some(m) if m == "rejected" => Milestone::Rejected(part()?),
+            Some(m) if m == "accepted" => Milestone::Accepted(part()?),
+            _ => return Err("Expected record.milestone to be a known milestone.".into()),
+        };
+
+        let timestamp =
+            number("timestamp").ok_or("Expected record.timestamp to be a number.")? as u64;
+
+        let year = number("year")
+            .filter(|year| (0.0..=f64::from(u16::MAX)).contains(year))
+            .ok_or("Expected record.year to be a year.")? as u16;
+
+        Ok(Record {
+            year,
+            day,
+            milestone,
+            timestamp,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{report, Milestone, Stats};
+    use crate::{day, template::event::Event};
+
+    /// 2024-12-01T05:00:00Z, when day 1 of 2024 unlocked.
+    const UNLOCK: u64 = 1_733_029_200;
+    const DAY: u64 = 86_400;
+
+    fn get_mock_stats() -> Stats {
+        let mut stats = Stats::default();
+        stats.record(2024, day!(1), Milestone::Scaffolded, UNLOCK + 60);
+        stats.record(2024, day!(1), Milestone::Rejected(1), UNLOCK + 600);
+        stats.record(2024, day!(1), Milestone::Accepted(1), UNLOCK + 660);
+        stats.record(2024, day!(1), Milestone::Accepted(2), UNLOCK + 1260);
+        stats.record(2024, day!(2), Milestone::Downloaded, UNLOCK + DAY + 30);
+        stats.record(2024, day!(2), Milestone::Accepted(1), UNLOCK + DAY + 90);
+        stats.record(2024, day!(2), Milestone::Accepted(2), UNLOCK + DAY + 150);
+        stats.record(2024, day!(3), Milestone::Scaffolded, UNLOCK + 3 * DAY);
+        stats.record(
+            2024,
+            day!(3),
+            Milestone::Accepted(1),
+            UNLOCK + 3 * DAY + 100,
+        );
+        stats.record(
+            2024,
+            day!(3),
+            Milestone::Accepted(2),
+            UNLOCK + 3 * DAY + 200,
+        );
+        // another event, which must not affect the 2024 report.
+        stats.record(2023, day!(1), Milestone::Scaffolded, UNLOCK - 365 * DAY);
+        stats.record(2023, day!(4), Milestone::Accepted(1), UNLOCK - 360 * DAY);
+        stats
+    }
+
+    #[test]
+    fn records_milestones_once() {
+        let mut stats = get_mock_stats();
+        assert!(!stats.record(2024, day!(1), Milestone::Scaffolded, UNLOCK + 5000));
+        assert!(stats.record(2025, day!(1), Milestone::Scaffolded, UNLOCK + 5000));
+        assert!(stats.record(2024, day!(1), Milestone::Rejected(2), UNLOCK + 5000));
+
+        let stats = stats.for_year(2024);
+        assert_eq!(
+            stats.first(day!(1), Milestone::Scaffolded),
+            Some(UNLOCK + 60)
+        );
+        assert_eq!(stats.wrong_attempts(day!(1)), 2);
+    }
+
+    #[test]
+    fn reports_star_times_and_streaks() {
+        let reports = report(&get_mock_stats(), &Event::builtin(2024));
+        assert_eq!(reports.len(), 3);
+
+        assert_eq!(reports[0].first_star, Some(Duration::from_secs(600)));
+        assert_eq!(reports[0].second_star, Some(Duration::from_secs(1200)));
+        assert_eq!(reports[0].wrong_attempts, 1);
+        assert_eq!(reports[0].streak, 1);
+        assert_eq!(reports[1].streak, 2);
+        // day 3 was solved a day late, which breaks the streak.
+        assert_eq!(reports[2].streak, 0);
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let stats = get_mock_stats();
+        let json = tinyjson::JsonValue::from(stats.clone())
+            .stringify()
+            .unwrap();
+        assert_eq!(Stats::try_from(json).unwrap().data, stats.data);
+    }
+
+    #[test]
+    fn rejects_invalid_records() {
+        let json = r#"{"data":[{"day":"01","milestone":"started","timestamp":0}]}"#;
+        assert!(Stats::try_from(json.to_string()).is_err());
+    }
+}
@@ -0,0 +1,158 @@
+/// Exit status of a command, aggregated over all days it ran.
+///
+/// | Code | Status          | Meaning                                                   |
+/// | :--: | --------------- | --------------------------------------------------------- |
+/// | 0    | `Success`       | everything ran successfully.                              |
+/// | 1    | `Failure`       | generic failure, e.g. I/O, config or aoc-cli errors.      |
+/// | 2    | `Usage`         | invalid command-line arguments.                           |
+/// | 3    | `BuildFailed`   | a solution did not compile.                               |
+/// | 4    | `RuntimeFailed` | a solution panicked or crashed.                           |
+/// | 5    | `WrongAnswer`   | a submitted answer was rejected.                          |
+/// | 6    | `Timeout`       | a solution exceeded the configured timeout and was killed. |
+use std::fmt::Display;
+use std::process::ExitStatus;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Success,
+    Failure,
+    Usage,
+    BuildFailed,
+    RuntimeFailed,
+    WrongAnswer,
+    Timeout,
+}
+
+impl Status {
+    const ALL: [Status; 7] = [
+        Status::Success,
+        Status::Failure,
+        Status::Usage,
+        Status::BuildFailed,
+        Status::RuntimeFailed,
+        Status::WrongAnswer,
+        Status::Timeout,
+    ];
+
+    pub fn code(self) -> u8 {
+        match self {
+            Status::Success => 0,
+            Status::Failure => 1,
+            Status::Usage => 2,
+            Status::BuildFailed => 3,
+            Status::RuntimeFailed => 4,
+            Status::WrongAnswer => 5,
+            Status::Timeout => 6,
+        }
+    }
+
+    pub fn is_success(self) -> bool {
+        self == Status::Success
+    }
+
+    /// Maps the exit status of a solution binary back to a [`Status`].
+    /// Every code in the table above maps back to its own variant. Panics (exit code 101)
+    /// and crashes (terminated by a signal) count as runtime failures.
+    pub fn from_exit(exit: ExitStatus) -> Self {
+        exit.code()
+            .and_then(|code| Self::ALL.into_iter().find(|s| i32::from(s.code()) == code))
+            .unwrap_or(Status::RuntimeFailed)
+    }
+
+    /// Rank used when aggregating, problems that prevent a solution from running at all rank highest.
+    fn severity(self) -> u8 {
+        match self {
+            Status::Success => 0,
+            Status::WrongAnswer => 1,
+            Status::Failure => 2,
+            Status::Timeout => 3,
+            Status::RuntimeFailed => 4,
+            Status::BuildFailed => 5,
+            Status::Usage => 6,
+        }
+    }
+
+    /// Combines two statuses, keeping the more severe one.
+    #[must_use]
+    pub fn and(self, other: Self) -> Self {
+        if other.severity() > self.severity() {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Status::Success => "success",
+            Status::Failure => "failure",
+            Status::Usage => "usage error",
+            Status::BuildFailed => "build failed",
+            Status::RuntimeFailed => "runtime failure",
+            Status::WrongAnswer => "wrong answer",
+            Status::Timeout => "timeout",
+        };
+        f.write_str(description)
+    }
+}
+
+impl From<Status> for std::process::ExitCode {
+    fn from(status: Status) -> Self {
+        std::process::ExitCode::from(status.code())
+    }
+}
+
+impl FromIterator<Status> for Status {
+    fn from_iter<I: IntoIterator<Item = Status>>(iter: I) -> Self {
+        iter.into_iter().fold(Status::Success, Status::and)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Status;
+
+    #[test]
+    fn aggregates_to_most_severe_status() {
+        assert_eq!(
+            Status::Success.and(Status::WrongAnswer),
+            Status::WrongAnswer
+        );
+        assert_eq!(
+            Status::BuildFailed.and(Status::Timeout),
+            Status::BuildFailed
+        );
+        assert_eq!(
+            [Status::Success, Status::RuntimeFailed, Status::WrongAnswer]
+                .into_iter()
+                .collect::<Status>(),
+            Status::RuntimeFailed
+        );
+        assert_eq!(
+            Vec::<Status>::new().into_iter().collect::<Status>(),
+            Status::Success
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn maps_child_exit_codes() {
+        use std::os::unix::process::ExitStatusExt;
+        use std::process::ExitStatus;
+
+        let exit = |code: i32| ExitStatus::from_raw(code << 8);
+        assert_eq!(Status::from_exit(exit(0)), Status::Success);
+        assert_eq!(Status::from_exit(exit(5)), Status::WrongAnswer);
+        for status in Status::ALL {
+            assert_eq!(Status::from_exit(exit(status.code().into())), status);
+        }
+        assert_eq!(Status::from_exit(exit(101)), Status::RuntimeFailed);
+        // terminated by SIGSEGV.
+        assert_eq!(
+            Status::from_exit(ExitStatus::from_raw(11)),
+            Status::RuntimeFailed
+        );
+    }
+}
@@ -1,16 +1,28 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
-
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+use crate::template::event::Event;
+use crate::template::{config, Day};
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
+    /// Time of the day's `parse` function, see `runner::time_parse`.
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    /// Numeric counterparts of the display strings above, in nanoseconds.
+    pub parse_nanos: Option<f64>,
+    pub part_1_nanos: Option<f64>,
+    pub part_2_nanos: Option<f64>,
+    /// Sum of both parts, in nanoseconds. They parse the input themselves, so the parse time is not added.
     pub total_nanos: f64,
 }
 
@@ -22,19 +34,40 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
+    /// Dehydrate timings to a JSON file, keeping the stored [`ConfigurationTimings`].
     pub fn store_file(&self) -> Result<(), Error> {
-        let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut json = JsonValue::from(self.clone());
+        if let (JsonValue::Object(map), Some(configurations)) =
+            (&mut json, stored_value("configurations"))
+        {
+            map.insert("configurations".into(), configurations);
+        }
+        let mut file = fs::File::create(config::get().timings_path())?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
+    /// Invalid entries are skipped with a warning.
     pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from)
-            .unwrap_or_default()
+        let (timings, errors) = Timings::read_from_file_checked();
+        for e in errors {
+            eprintln!("Skipping invalid timings: {e}");
+        }
+        timings
+    }
+
+    /// Like [`Timings::read_from_file`], but returns the problems instead of printing them.
+    /// A missing file is not a problem.
+    pub fn read_from_file_checked() -> (Self, Vec<String>) {
+        let path = config::get().timings_path();
+        match fs::read_to_string(&path) {
+            Ok(json) => Timings::parse(&json),
+            Err(e) if e.kind() == ErrorKind::NotFound => (Timings::default(), vec![]),
+            Err(e) => (
+                Timings::default(),
+                vec![format!("failed to read \"{}\": {e}", path.display())],
+            ),
+        }
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
@@ -51,7 +84,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -60,19 +93,128 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
-            .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+    /// Whether all parts of `day` are benched. Single-part days only need a first part.
+    pub fn is_day_complete(&self, day: Day, event: &Event) -> bool {
+        self.data.iter().any(|t| {
+            t.day == day && t.part_1.is_some() && (t.part_2.is_some() || event.is_single_part(day))
+        })
+    }
+}
+
+/// Formats for exporting timings to other tools, e.g. spreadsheets or notebooks.
+/// Every format holds one row per day with times in nanoseconds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    /// An array of objects, one per day. Readable with `pandas.read_json`.
+    Json,
+    Markdown,
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(ExportFormat::Csv),
+            "json" => Ok(ExportFormat::Json),
+            "markdown" | "md" => Ok(ExportFormat::Markdown),
+            _ => Err(format!(
+                "unknown export format `{s}`, expecting `csv`, `json` or `markdown`."
+            )),
+        }
+    }
+}
+
+const EXPORT_COLUMNS: [&str; 5] = [
+    "day",
+    "parse_nanos",
+    "part_1_nanos",
+    "part_2_nanos",
+    "total_nanos",
+];
+
+impl Timings {
+    /// Renders the timings in `format`. Missing times are left empty, or `null` in JSON.
+    pub fn export(&self, format: ExportFormat) -> String {
+        let rows = self.data.iter().map(|t| {
+            [
+                Some(f64::from(t.day.into_inner())),
+                t.parse_nanos,
+                t.part_1_nanos,
+                t.part_2_nanos,
+                Some(t.total_nanos),
+            ]
+        });
+
+        match format {
+            ExportFormat::Csv => {
+                let mut out = EXPORT_COLUMNS.join(",") + "\n";
+                for row in rows {
+                    let cells = row.map(|n| n.map_or(String::new(), |n| n.to_string()));
+                    out.push_str(&cells.join(","));
+                    out.push('\n');
+                }
+                out
+            }
+            ExportFormat::Markdown => {
+                let mut out = format!("| {} |\n", EXPORT_COLUMNS.join(" | "));
+                out.push_str(&format!("| {} |\n", ["---:"; 5].join(" | ")));
+                for row in rows {
+                    let cells = row.map(|n| n.map_or("-".into(), |n| n.to_string()));
+                    out.push_str(&format!("| {} |\n", cells.join(" | ")));
+                }
+                out
+            }
+            ExportFormat::Json => {
+                let records = rows
+                    .map(|row| {
+                        let values = row.map(|n| n.map_or(JsonValue::Null, JsonValue::Number));
+                        JsonValue::Object(
+                            EXPORT_COLUMNS
+                                .iter()
+                                .map(ToString::to_string)
+                                .zip(values)
+                                .collect(),
+                        )
+                    })
+                    .collect();
+                JsonValue::Array(records)
+                    .format()
+                    .map(|json| json + "\n")
+                    .unwrap_or_default()
+            }
+        }
+    }
+}
+
+/// Converts a duration as printed by the runner, e.g. `72.1µs`, to nanoseconds.
+// for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+pub fn parse_duration(s: &str) -> Option<f64> {
+    let parse_to_float = |postfix: &str| s.split(postfix).next()?.parse::<f64>().ok();
+
+    match s {
+        s if s.contains("ns") => parse_to_float("ns"),
+        s if s.contains("µs") => parse_to_float("µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float("ms").map(|x| x * 1_000_000_f64),
+        _ => parse_to_float("s").map(|x| x * 1_000_000_000_f64),
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
+/// Version of the timings file written by this template.
+/// Files without a `version` key are version 1, which stored part times as display strings only.
+pub const TIMINGS_VERSION: u32 = 2;
+
 impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert(
+            "version".into(),
+            JsonValue::Number(f64::from(TIMINGS_VERSION)),
+        );
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
@@ -82,26 +224,97 @@ impl From<Timings> for JsonValue {
     }
 }
 
+impl Timings {
+    /// Parses a timings file, migrating older versions and keeping every valid entry.
+    /// Returns an error for each entry that was dropped, or a single error if the file
+    /// could not be read at all.
+    pub fn parse(value: &str) -> (Self, Vec<String>) {
+        match parse_entries(value) {
+            Ok(entries) => {
+                let mut timings = Timings::default();
+                let mut errors = vec![];
+                for (i, entry) in entries.into_iter().enumerate() {
+                    match Timing::try_from(&entry) {
+                        Ok(timing) => timings.data.push(timing),
+                        Err(e) => errors.push(format!("data[{i}]: {e}")),
+                    }
+                }
+                (timings, errors)
+            }
+            Err(e) => (Timings::default(), vec![e]),
+        }
+    }
+}
+
+/// Reads the entries of a timings file and migrates them to [`TIMINGS_VERSION`].
+fn parse_entries(value: &str) -> Result<Vec<JsonValue>, String> {
+    let json = JsonValue::from_str(value).or(Err("not valid JSON file."))?;
+
+    let json = json
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("expected JSON document to be an object.")?;
+
+    let version = match json.get("version") {
+        None => 1,
+        Some(JsonValue::Number(n)) if n.fract() == 0.0 && *n >= 1.0 => {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let version = *n as u32;
+            version
+        }
+        Some(_) => return Err("expected `json.version` to be a positive integer.".into()),
+    };
+
+    if version > TIMINGS_VERSION {
+        return Err(format!(
+            "file has version {version}, but this template only reads up to version {TIMINGS_VERSION}."
+        ));
+    }
+
+    let mut entries = json
+        .get("data")
+        .ok_or("expected JSON document to have key `data`.")?
+        .get::<Vec<JsonValue>>()
+        .ok_or("expected `json.data` to be an array.")?
+        .clone();
+
+    if version < 2 {
+        entries.iter_mut().for_each(migrate_v1);
+    }
+
+    Ok(entries)
+}
+
+/// Version 1 stored part times as display strings only, and files written before parse
+/// times were recorded have no `parse` key. The nanoseconds are recovered from the strings.
+fn migrate_v1(entry: &mut JsonValue) {
+    let JsonValue::Object(map) = entry else {
+        return;
+    };
+
+    map.entry("parse".into()).or_insert(JsonValue::Null);
+
+    for key in ["parse", "part_1", "part_2"] {
+        let nanos = match map.get(key) {
+            Some(JsonValue::String(time)) => {
+                parse_duration(time).map_or(JsonValue::Null, JsonValue::Number)
+            }
+            _ => JsonValue::Null,
+        };
+        map.entry(format!("{key}_nanos")).or_insert(nanos);
+    }
+}
+
 impl TryFrom<String> for Timings {
     type Error = String;
 
+    /// Parses a timings file, failing if any entry is invalid.
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
-
-        let json_data = json
-            .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
-            .get("data")
-            .ok_or("expected JSON document to have key `data`.")?
-            .get::<Vec<JsonValue>>()
-            .ok_or("expected `json.data` to be an array.")?;
-
-        Ok(Timings {
-            data: json_data
-                .iter()
-                .map(Timing::try_from)
-                .collect::<Result<_, _>>()?,
-        })
+        let (timings, errors) = Timings::parse(&value);
+        if errors.is_empty() {
+            Ok(timings)
+        } else {
+            Err(errors.join("\n"))
+        }
     }
 }
 
@@ -114,24 +327,24 @@ impl From<&Timing> for JsonValue {
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
-
-        map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+        for (key, time) in [
+            ("parse", &value.parse),
+            ("part_1", &value.part_1),
+            ("part_2", &value.part_2),
+        ] {
+            map.insert(
+                key.into(),
+                time.clone().map_or(JsonValue::Null, JsonValue::String),
+            );
+        }
 
-        map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+        for (key, nanos) in [
+            ("parse_nanos", value.parse_nanos),
+            ("part_1_nanos", value.part_1_nanos),
+            ("part_2_nanos", value.part_2_nanos),
+        ] {
+            map.insert(key.into(), nanos.map_or(JsonValue::Null, JsonValue::Number));
+        }
 
         JsonValue::Object(map)
     }
@@ -151,15 +364,17 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        let string = |key: &str| match json.get(key) {
+            Some(JsonValue::Null) => Ok(None),
+            Some(JsonValue::String(s)) => Ok(Some(s.clone())),
+            _ => Err(format!("Expected timing.{key} to be null or string.")),
+        };
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+        let nanos = |key: &str| match json.get(key) {
+            Some(JsonValue::Null) => Ok(None),
+            Some(JsonValue::Number(n)) if *n >= 0.0 => Ok(Some(*n)),
+            _ => Err(format!("Expected timing.{key} to be null or a number.")),
+        };
 
         let total_nanos = json
             .get("total_nanos")
@@ -168,8 +383,12 @@ impl TryFrom<&JsonValue> for Timing {
 
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            parse: string("parse")?,
+            part_1: string("part_1")?,
+            part_2: string("part_2")?,
+            parse_nanos: nanos("parse_nanos")?,
+            part_1_nanos: nanos("part_1_nanos")?,
+            part_2_nanos: nanos("part_2_nanos")?,
             total_nanos,
         })
     }
@@ -177,6 +396,250 @@ impl TryFrom<&JsonValue> for Timing {
 
 /* -------------------------------------------------------------------------- */
 
+/// Reads `key` of the stored timings file, if the file is a valid JSON object.
+fn stored_value(key: &str) -> Option<JsonValue> {
+    let json = JsonValue::from_str(&fs::read_to_string(config::get().timings_path()).ok()?).ok()?;
+    json.get::<HashMap<String, JsonValue>>()?.get(key).cloned()
+}
+
+/// Timings of `cargo time --profiles`, by the name of the build configuration, e.g. `lto`.
+/// Stored under the `configurations` key of the timings file, next to the timings of the default
+/// release build that the README and reports use.
+#[derive(Clone, Debug, Default)]
+pub struct ConfigurationTimings {
+    pub data: BTreeMap<String, Timings>,
+}
+
+impl ConfigurationTimings {
+    /// Rehydrate the configurations from the timings file, keeping every valid entry.
+    /// Returns the problems with the others, a missing file is not a problem.
+    pub fn read_from_file_checked() -> (Self, Vec<String>) {
+        let path = config::get().timings_path();
+        match fs::read_to_string(&path) {
+            Ok(json) => ConfigurationTimings::parse(&json),
+            Err(e) if e.kind() == ErrorKind::NotFound => (ConfigurationTimings::default(), vec![]),
+            Err(e) => (
+                ConfigurationTimings::default(),
+                vec![format!("failed to read \"{}\": {e}", path.display())],
+            ),
+        }
+    }
+
+    /// Writes the configurations to the timings file, keeping the rest of the file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let path = config::get().timings_path();
+        let mut map = match fs::read_to_string(&path) {
+            Ok(json) => JsonValue::from_str(&json)
+                .ok()
+                .and_then(|json| json.get::<HashMap<String, JsonValue>>().cloned())
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidData,
+                        format!("\"{}\" is not a valid timings file.", path.display()),
+                    )
+                })?,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                match JsonValue::from(Timings::default()) {
+                    JsonValue::Object(map) => map,
+                    _ => HashMap::new(),
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        map.insert("configurations".into(), JsonValue::from(self));
+        let mut file = fs::File::create(path)?;
+        JsonValue::Object(map).format_to(&mut file)
+    }
+
+    /// Merges the timings of `configuration`, overwriting stored days.
+    pub fn merge(&mut self, configuration: &str, timings: &Timings) {
+        let stored = self.data.entry(configuration.into()).or_default();
+        *stored = stored.merge(timings);
+    }
+
+    /// Parses the `configurations` of a timings file, keeping every valid entry.
+    /// A file without configurations has none.
+    pub fn parse(value: &str) -> (Self, Vec<String>) {
+        let mut configurations = ConfigurationTimings::default();
+        let mut errors = vec![];
+
+        let Some(json) = JsonValue::from_str(value)
+            .ok()
+            .and_then(|json| json.get::<HashMap<String, JsonValue>>().cloned())
+        else {
+            return (configurations, vec!["not valid JSON file.".into()]);
+        };
+
+        let Some(value) = json.get("configurations") else {
+            return (configurations, errors);
+        };
+        let Some(map) = value.get::<HashMap<String, JsonValue>>() else {
+            return (
+                configurations,
+                vec!["expected `json.configurations` to be an object.".into()],
+            );
+        };
+
+        for (name, entries) in map {
+            let Some(entries) = entries.get::<Vec<JsonValue>>() else {
+                errors.push(format!("expected `configurations.{name}` to be an array."));
+                continue;
+            };
+            let timings = configurations.data.entry(name.clone()).or_default();
+            for (i, entry) in entries.iter().enumerate() {
+                match Timing::try_from(entry) {
+                    Ok(timing) => timings.data.push(timing),
+                    Err(e) => errors.push(format!("configurations.{name}[{i}]: {e}")),
+                }
+            }
+        }
+
+        (configurations, errors)
+    }
+}
+
+impl From<&ConfigurationTimings> for JsonValue {
+    fn from(value: &ConfigurationTimings) -> Self {
+        JsonValue::Object(
+            value
+                .data
+                .iter()
+                .map(|(name, timings)| {
+                    let entries = timings.data.iter().map(JsonValue::from).collect();
+                    (name.clone(), JsonValue::Array(entries))
+                })
+                .collect(),
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Total time of a day when its timings were stored.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    pub day: Day,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub total_nanos: f64,
+}
+
+/// Every stored benchmark, oldest first, to show how timings changed over time.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    pub data: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Dehydrate the history to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(config::get().history_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the history from a JSON file. If not present, returns an empty history.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(config::get().history_path())
+            .map_err(|x| x.to_string())
+            .and_then(History::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Appends the totals of all benched days in `timings`.
+    pub fn record(&mut self, timings: &Timings) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        for timing in timings.data.iter().filter(|t| t.total_nanos > 0.0) {
+            self.data.push(HistoryEntry {
+                day: timing.day,
+                timestamp,
+                total_nanos: timing.total_nanos,
+            });
+        }
+    }
+
+    /// Totals of `day`, oldest first.
+    pub fn totals(&self, day: Day) -> Vec<f64> {
+        self.data
+            .iter()
+            .filter(|e| e.day == day)
+            .map(|e| e.total_nanos)
+            .collect()
+    }
+}
+
+impl From<History> for JsonValue {
+    fn from(value: History) -> Self {
+        let data = value
+            .data
+            .iter()
+            .map(|entry| {
+                let mut map: HashMap<String, JsonValue> = HashMap::new();
+                map.insert("day".into(), JsonValue::String(entry.day.to_string()));
+                #[allow(clippy::cast_precision_loss)]
+                map.insert(
+                    "timestamp".into(),
+                    JsonValue::Number(entry.timestamp as f64),
+                );
+                map.insert("total_nanos".into(), JsonValue::Number(entry.total_nanos));
+                JsonValue::Object(map)
+            })
+            .collect();
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("data".into(), JsonValue::Array(data));
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for History {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        let data = json_data
+            .iter()
+            .map(|value| {
+                let json = value
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("Expected history entry to be a JSON object.")?;
+                let number = |key: &str| {
+                    json.get(key)
+                        .and_then(|v| v.get::<f64>().copied())
+                        .ok_or(format!("Expected history.{key} to be a number."))
+                };
+
+                Ok(HistoryEntry {
+                    day: json
+                        .get("day")
+                        .and_then(|v| v.get::<String>())
+                        .and_then(|day| Day::from_str(day).ok())
+                        .ok_or("Expected history.day to be a Day struct.")?,
+                    timestamp: number("timestamp")? as u64,
+                    total_nanos: number("total_nanos")?,
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(History { data })
+    }
+}
+
 #[cfg(feature = "test_lib")]
 mod tests {
     use crate::day;
@@ -188,20 +651,32 @@ mod tests {
             data: vec![
                 Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    parse_nanos: None,
+                    part_1_nanos: Some(10_000_000.0),
+                    part_2_nanos: Some(20_000_000.0),
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    parse_nanos: None,
+                    part_1_nanos: Some(30_000_000.0),
+                    part_2_nanos: Some(40_000_000.0),
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
+                    parse: None,
                     part_1: Some("40ms".into()),
                     part_2: None,
+                    parse_nanos: None,
+                    part_1_nanos: Some(40_000_000.0),
+                    part_2_nanos: None,
                     total_nanos: 4e+10,
                 },
             ],
@@ -230,6 +705,60 @@ mod tests {
             assert_eq!(timings.data.len(), 0);
         }
 
+        #[test]
+        fn migrates_timings_without_numeric_fields() {
+            let json = r#"{ "data": [{ "day": "01", "parse": "1.5µs", "part_1": "74.13ms", "part_2": null, "total_nanos": 74131500 }] }"#.to_string();
+            let timing = Timings::try_from(json).unwrap().data.remove(0);
+            assert_eq!(timing.parse_nanos, Some(1_500.0));
+            assert_eq!(timing.part_1_nanos, Some(74_130_000.0));
+            assert_eq!(timing.part_2_nanos, None);
+        }
+
+        #[test]
+        fn prefers_numeric_fields() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1.0ms", "part_1_nanos": 1042.5, "part_2": null, "part_2_nanos": null, "total_nanos": 1042.5 }] }"#.to_string();
+            let timing = Timings::try_from(json).unwrap().data.remove(0);
+            assert_eq!(timing.part_1_nanos, Some(1042.5));
+            assert_eq!(timing.part_2_nanos, None);
+        }
+
+        #[test]
+        fn requires_numeric_fields_in_current_version() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "parse": null, "part_1": "1.0ms", "part_2": null, "total_nanos": 1000000 }] }"#;
+            let (timings, errors) = Timings::parse(json);
+            assert!(timings.data.is_empty());
+            assert_eq!(
+                errors,
+                ["data[0]: Expected timing.parse_nanos to be null or a number."]
+            );
+        }
+
+        #[test]
+        fn keeps_valid_entries() {
+            let json = r#"{ "version": 2, "data": [
+                { "day": "01", "parse": null, "part_1": 5, "part_2": null, "parse_nanos": null, "part_1_nanos": 5, "part_2_nanos": null, "total_nanos": 5 },
+                { "day": "02", "parse": null, "part_1": "5ns", "part_2": null, "parse_nanos": null, "part_1_nanos": 5, "part_2_nanos": null, "total_nanos": 5 }
+            ] }"#;
+            let (timings, errors) = Timings::parse(json);
+            assert_eq!(timings.data.len(), 1);
+            assert_eq!(timings.data[0].day, day!(2));
+            assert_eq!(
+                errors,
+                ["data[0]: Expected timing.part_1 to be null or string."]
+            );
+            assert!(Timings::try_from(json.to_string()).is_err());
+        }
+
+        #[test]
+        fn rejects_newer_versions() {
+            let (timings, errors) = Timings::parse(r#"{ "version": 3, "data": [] }"#);
+            assert!(timings.data.is_empty());
+            assert_eq!(
+                errors,
+                ["file has version 3, but this template only reads up to version 2."]
+            );
+        }
+
         #[test]
         #[should_panic]
         fn panics_for_invalid_json() {
@@ -247,6 +776,7 @@ mod tests {
 
     mod serialization {
         use super::get_mock_timings;
+        use crate::template::timings::{ConfigurationTimings, Timings};
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
@@ -266,11 +796,110 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn round_trips_numeric_fields() {
+            let timings = get_mock_timings();
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            assert!(json.contains(r#""version":2"#));
+            let parsed = Timings::try_from(json).unwrap();
+            for (a, b) in timings.data.iter().zip(&parsed.data) {
+                assert_eq!(a.part_1_nanos, b.part_1_nanos);
+                assert_eq!(a.part_2_nanos, b.part_2_nanos);
+            }
+        }
+
+        #[test]
+        fn round_trips_configurations() {
+            let mut configurations = ConfigurationTimings::default();
+            configurations.merge("lto", &get_mock_timings());
+            configurations.merge("native", &get_mock_timings());
+
+            let mut json = JsonValue::from(Timings::default());
+            if let JsonValue::Object(map) = &mut json {
+                map.insert("configurations".into(), JsonValue::from(&configurations));
+            }
+            let json = json.stringify().unwrap();
+
+            let (parsed, errors) = ConfigurationTimings::parse(&json);
+            assert!(errors.is_empty());
+            assert_eq!(parsed.data.keys().collect::<Vec<_>>(), ["lto", "native"]);
+            assert_eq!(parsed.data["lto"].data.len(), 3);
+            assert!(Timings::try_from(json).unwrap().data.is_empty());
+        }
+
+        #[test]
+        fn skips_invalid_configuration_entries() {
+            let json = r#"{ "version": 2, "data": [], "configurations": { "lto": [{ "day": "01" }], "native": 5 } }"#;
+            let (parsed, errors) = ConfigurationTimings::parse(json);
+            assert!(parsed.data["lto"].data.is_empty());
+            assert_eq!(errors.len(), 2);
+            assert!(
+                errors.contains(&"expected `configurations.native` to be an array.".to_string())
+            );
+
+            let (parsed, errors) = ConfigurationTimings::parse(r#"{ "data": [] }"#);
+            assert!(parsed.data.is_empty() && errors.is_empty());
+        }
+    }
+
+    mod export {
+        use super::get_mock_timings;
+        use crate::template::timings::ExportFormat;
+        use std::collections::HashMap;
+        use std::str::FromStr;
+        use tinyjson::JsonValue;
+
+        #[test]
+        fn exports_csv() {
+            let csv = get_mock_timings().export(ExportFormat::Csv);
+            let lines: Vec<&str> = csv.lines().collect();
+            assert_eq!(
+                lines,
+                [
+                    "day,parse_nanos,part_1_nanos,part_2_nanos,total_nanos",
+                    "1,,10000000,20000000,30000000000",
+                    "2,,30000000,40000000,70000000000",
+                    "4,,40000000,,40000000000",
+                ]
+            );
+        }
+
+        #[test]
+        fn exports_markdown() {
+            let markdown = get_mock_timings().export(ExportFormat::Markdown);
+            let lines: Vec<&str> = markdown.lines().collect();
+            assert_eq!(
+                lines[0],
+                "| day | parse_nanos | part_1_nanos | part_2_nanos | total_nanos |"
+            );
+            assert_eq!(lines[1], "| ---: | ---: | ---: | ---: | ---: |");
+            assert_eq!(lines[4], "| 4 | - | 40000000 | - | 40000000000 |");
+        }
+
+        #[test]
+        fn exports_json_records() {
+            let json = get_mock_timings().export(ExportFormat::Json);
+            let value = JsonValue::from_str(&json).unwrap();
+            let records: &Vec<JsonValue> = value.get().unwrap();
+            assert_eq!(records.len(), 3);
+            let record: &HashMap<String, JsonValue> = records[2].get().unwrap();
+            assert_eq!(record["day"], JsonValue::Number(4.0));
+            assert_eq!(record["part_1_nanos"], JsonValue::Number(40_000_000.0));
+            assert_eq!(record["part_2_nanos"], JsonValue::Null);
+        }
+
+        #[test]
+        fn parses_formats() {
+            assert_eq!("md".parse(), Ok(ExportFormat::Markdown));
+            assert!("xlsx".parse::<ExportFormat>().is_err());
+        }
     }
 
     mod is_day_complete {
         use crate::{
             day,
+            template::event::Event,
             template::timings::{Timing, Timings},
         };
 
@@ -279,13 +908,20 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    parse_nanos: None,
+                    part_1_nanos: Some(1_000_000.0),
+                    part_2_nanos: Some(2_000_000.0),
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(
+                timings.is_day_complete(day!(1), &Event::builtin(2024)),
+                true
+            );
         }
 
         #[test]
@@ -293,13 +929,41 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: Some("1ms".into()),
                     part_2: None,
+                    parse_nanos: None,
+                    part_1_nanos: Some(1_000_000.0),
+                    part_2_nanos: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(
+                timings.is_day_complete(day!(1), &Event::builtin(2024)),
+                false
+            );
+        }
+
+        #[test]
+        fn handles_single_part_days() {
+            let timings = Timings {
+                data: vec![Timing {
+                    day: day!(25),
+                    parse: None,
+                    part_1: Some("1ms".into()),
+                    part_2: None,
+                    parse_nanos: None,
+                    part_1_nanos: Some(1_000_000.0),
+                    part_2_nanos: None,
+                    total_nanos: 1_000_000_000_f64,
+                }],
+            };
+
+            assert_eq!(
+                timings.is_day_complete(day!(25), &Event::builtin(2024)),
+                true
+            );
         }
 
         #[test]
@@ -307,13 +971,20 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    parse_nanos: None,
+                    part_1_nanos: None,
+                    part_2_nanos: None,
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(
+                timings.is_day_complete(day!(1), &Event::builtin(2024)),
+                false
+            );
         }
     }
 
@@ -331,8 +1002,12 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    parse_nanos: None,
+                    part_1_nanos: None,
+                    part_2_nanos: None,
                     total_nanos: 0_f64,
                 }],
             };
@@ -351,8 +1026,12 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    parse_nanos: None,
+                    part_1_nanos: None,
+                    part_2_nanos: None,
                     total_nanos: 0_f64,
                 }],
             };
@@ -0,0 +1,319 @@
+/// Parser for the small subset of TOML used by `aoc.toml`.
+/// Supports comments, `[table]` / `[table.sub]` headers and single-line `key = value` pairs,
+/// where a value is a string, an integer, a float, a boolean or an array of those.
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    Array(Vec<Value>),
+}
+
+impl Value {
+    /// Human-readable name of the value's type, used in error messages.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::String(_) => "a string",
+            Value::Integer(_) => "an integer",
+            Value::Float(_) => "a float",
+            Value::Boolean(_) => "a boolean",
+            Value::Array(_) => "an array",
+        }
+    }
+}
+
+/// A single `key = value` pair together with the table it belongs to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    /// Dotted path of the enclosing table, empty for top-level keys.
+    pub table: String,
+    pub key: String,
+    pub value: Value,
+    pub line: usize,
+}
+
+impl Entry {
+    /// Fully qualified key, e.g. `bench.target_ms`.
+    pub fn path(&self) -> String {
+        if self.table.is_empty() {
+            self.key.clone()
+        } else {
+            format!("{}.{}", self.table, self.key)
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+pub fn parse(source: &str) -> Result<Vec<Entry>, ParseError> {
+    let mut entries: Vec<Entry> = vec![];
+    let mut table = String::new();
+
+    for (i, raw_line) in source.lines().enumerate() {
+        let line_no = i + 1;
+        let err = |message: String| ParseError {
+            line: line_no,
+            message,
+        };
+
+        let line = strip_comment(raw_line).trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            let header = header
+                .strip_suffix(']')
+                .ok_or_else(|| err("unterminated table header.".into()))?;
+            table = parse_key(header).map_err(err)?;
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| err(format!("expected `key = value`, found `{line}`.")))?;
+
+        let key = parse_key(key).map_err(err)?;
+        let value = parse_value(value.trim()).map_err(err)?;
+
+        // dotted keys (`bench.target_ms = 1`) are folded into their table.
+        let (table, key) = match key.rsplit_once('.') {
+            Some((prefix, key)) if table.is_empty() => (prefix.to_string(), key.to_string()),
+            Some((prefix, key)) => (format!("{table}.{prefix}"), key.to_string()),
+            None => (table.clone(), key),
+        };
+
+        if entries.iter().any(|e| e.table == table && e.key == key) {
+            return Err(err(format!("duplicate key `{key}`.")));
+        }
+
+        entries.push(Entry {
+            table,
+            key,
+            value,
+            line: line_no,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Removes a trailing `# comment`, ignoring `#` characters inside of strings.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            '\\' if in_string => escaped = !escaped,
+            '"' if !escaped => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => escaped = false,
+        }
+    }
+
+    line
+}
+
+fn parse_key(key: &str) -> Result<String, String> {
+    let parts: Vec<String> = key
+        .split('.')
+        .map(|part| {
+            let part = part.trim();
+            if let Some(quoted) = part.strip_prefix('"').and_then(|p| p.strip_suffix('"')) {
+                return Ok(quoted.to_string());
+            }
+            if part.is_empty()
+                || !part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                return Err(format!("invalid key `{}`.", key.trim()));
+            }
+            Ok(part.to_string())
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(parts.join("."))
+}
+
+fn parse_value(value: &str) -> Result<Value, String> {
+    if value.is_empty() {
+        return Err("missing value.".into());
+    }
+
+    if let Some(inner) = value.strip_prefix('"') {
+        let inner = inner
+            .strip_suffix('"')
+            .ok_or_else(|| format!("unterminated string `{value}`."))?;
+        return unescape(inner).map(Value::String);
+    }
+
+    if let Some(inner) = value.strip_prefix('[') {
+        let inner = inner
+            .strip_suffix(']')
+            .ok_or_else(|| format!("unterminated array `{value}`."))?;
+        return split_array(inner)?
+            .into_iter()
+            .map(parse_value)
+            .collect::<Result<_, _>>()
+            .map(Value::Array);
+    }
+
+    match value {
+        "true" => return Ok(Value::Boolean(true)),
+        "false" => return Ok(Value::Boolean(false)),
+        _ => {}
+    }
+
+    let number = value.replace('_', "");
+
+    if let Ok(int) = number.parse::<i64>() {
+        return Ok(Value::Integer(int));
+    }
+
+    if let Ok(float) = number.parse::<f64>() {
+        return Ok(Value::Float(float));
+    }
+
+    Err(format!(
+        "invalid value `{value}`. Strings need to be wrapped in double quotes."
+    ))
+}
+
+fn unescape(s: &str) -> Result<String, String> {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '"' {
+            return Err(format!("unescaped quote in string `{s}`."));
+        }
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('\\') => out.push('\\'),
+            Some('"') => out.push('"'),
+            Some(x) => return Err(format!("unsupported escape sequence `\\{x}`.")),
+            None => return Err("dangling `\\` at end of string.".into()),
+        }
+    }
+
+    Ok(out)
+}
+
+/// Splits the inside of an array literal on top-level commas.
+fn split_array(inner: &str) -> Result<Vec<&str>, String> {
+    let mut items = vec![];
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut start = 0;
+
+    for (i, c) in inner.char_indices() {
+        match c {
+            '"' if !inner[..i].ends_with('\\') => in_string = !in_string,
+            '[' if !in_string => depth += 1,
+            ']' if !in_string => depth -= 1,
+            ',' if !in_string && depth == 0 => {
+                items.push(inner[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    let last = inner[start..].trim();
+    if !last.is_empty() {
+        items.push(last);
+    }
+
+    if items.iter().any(|item| item.is_empty()) {
+        return Err(format!("empty element in array `[{inner}]`."));
+    }
+
+    Ok(items)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, Value};
+
+    #[test]
+    fn parses_tables_and_values() {
+        let entries = parse(
+            r#"
+            # project settings
+            year = 2024
+            data_dir = "data" # trailing comment
+
+            [bench]
+            target_ms = 1_000
+            ratio = 0.5
+            enabled = true
+            days = [1, 2, 3]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(entries.len(), 6);
+        assert_eq!(entries[0].path(), "year");
+        assert_eq!(entries[0].value, Value::Integer(2024));
+        assert_eq!(entries[1].value, Value::String("data".into()));
+        assert_eq!(entries[2].path(), "bench.target_ms");
+        assert_eq!(entries[2].value, Value::Integer(1000));
+        assert_eq!(entries[3].value, Value::Float(0.5));
+        assert_eq!(entries[4].value, Value::Boolean(true));
+        assert_eq!(
+            entries[5].value,
+            Value::Array(vec![
+                Value::Integer(1),
+                Value::Integer(2),
+                Value::Integer(3)
+            ])
+        );
+    }
+
+    #[test]
+    fn folds_dotted_keys() {
+        let entries = parse("readme.path = \"README.md\"\n[events]\n2024.days = 25").unwrap();
+        assert_eq!(entries[0].table, "readme");
+        assert_eq!(entries[0].key, "path");
+        assert_eq!(entries[1].path(), "events.2024.days");
+    }
+
+    #[test]
+    fn keeps_hashes_inside_strings() {
+        let entries = parse(r#"marker = "<!--- # --->""#).unwrap();
+        assert_eq!(entries[0].value, Value::String("<!--- # --->".into()));
+    }
+
+    #[test]
+    fn reports_line_of_error() {
+        let err = parse("year = 2024\ndata_dir = data").unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+
+    #[test]
+    fn rejects_duplicate_keys() {
+        let err = parse("[bench]\nmin_samples = 1\nmin_samples = 2").unwrap_err();
+        assert_eq!(err.line, 3);
+    }
+}
@@ -0,0 +1,609 @@
+/// Records frames of a grid while a solution runs and exports them as an animation.
+/// Solutions call [`frame`] or [`grid`] wherever the state changes. Unless the run records, e.g. via
+/// `cargo solve <day> --viz <dir>`, these return right away without rendering anything.
+///
+/// ```ignore
+/// for mov in movements {
+///     map = update_map(map, &mut pos, mov);
+///     viz::grid(&map);
+/// }
+/// ```
+///
+/// Every character of a frame becomes a cell of the image, colored by [`color`].
+use std::cell::RefCell;
+use std::fmt::{Display, Write as _};
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use flate2::write::ZlibEncoder;
+use flate2::{Compression, Crc};
+
+use crate::template::hash::HashMap;
+
+/// Frames kept per part. Longer runs keep every 2nd, 4th, ... frame, so they are still covered evenly.
+pub const MAX_FRAMES: usize = 300;
+
+/// Time each frame is shown, and the last one before the animation loops.
+const FRAME_MS: u16 = 50;
+const LAST_FRAME_MS: u16 = 2000;
+
+/// Largest side of an image in pixels that cells are scaled up to, at most [`MAX_SCALE`] each.
+const MAX_SIDE: usize = 800;
+const MAX_SCALE: usize = 8;
+
+thread_local! {
+    static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+}
+
+/// Frames recorded on one thread.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Recording {
+    pub frames: Vec<String>,
+    /// Frames offered by the solution, including the skipped ones.
+    pub offered: usize,
+    /// Only every `stride`-th offered frame is rendered.
+    stride: usize,
+}
+
+impl Recording {
+    pub fn new() -> Self {
+        Self {
+            frames: vec![],
+            offered: 0,
+            stride: 1,
+        }
+    }
+
+    /// Renders and keeps the frame if it falls on the stride, halving the kept frames when full.
+    pub fn push(&mut self, render: impl FnOnce() -> String) {
+        if self.offered.is_multiple_of(self.stride) && self.frames.len() == MAX_FRAMES {
+            let mut index = 0;
+            self.frames.retain(|_| {
+                index += 1;
+                index % 2 == 1
+            });
+            self.stride *= 2;
+        }
+        if self.offered.is_multiple_of(self.stride) {
+            self.frames.push(render());
+        }
+        self.offered += 1;
+    }
+}
+
+/// Starts recording frames on the current thread, dropping any previous recording.
+pub fn start() {
+    RECORDING.with(|recording| recording.replace(Some(Recording::new())));
+}
+
+/// Stops recording and returns the frames, `None` if nothing was recording.
+pub fn take() -> Option<Recording> {
+    RECORDING.with(|recording| recording.take())
+}
+
+pub fn is_recording() -> bool {
+    RECORDING.with(|recording| recording.borrow().is_some())
+}
+
+/// Records a frame. `render` is only called while recording, and not for skipped frames.
+#[inline]
+pub fn frame(render: impl FnOnce() -> String) {
+    RECORDING.with(|recording| {
+        if let Some(recording) = recording.borrow_mut().as_mut() {
+            recording.push(render);
+        }
+    });
+}
+
+/// Records a grid of characters as a frame, see [`frame`].
+#[inline]
+pub fn grid(rows: &[Vec<char>]) {
+    frame(|| {
+        rows.iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    });
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// A shell script that replays the frames in the terminal.
+    Terminal,
+    /// A folder of PPM images, one per frame.
+    Ppm,
+    /// A folder of PNG images, one per frame.
+    Png,
+    /// An animated GIF that loops.
+    #[default]
+    Gif,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "term" | "terminal" => Ok(Format::Terminal),
+            "ppm" => Ok(Format::Ppm),
+            "png" => Ok(Format::Png),
+            "gif" => Ok(Format::Gif),
+            _ => Err(format!(
+                "unknown visualization format `{s}`, expecting `term`, `ppm`, `png` or `gif`."
+            )),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Format::Terminal => "term",
+            Format::Ppm => "ppm",
+            Format::Png => "png",
+            Format::Gif => "gif",
+        })
+    }
+}
+
+/// Where `--viz` writes the recording of each part, passed to the solution binary by `solve`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VizOptions {
+    /// Path of the exports without the part, e.g. `out/14` for `out/14-1.gif` and `out/14-2.gif`.
+    pub prefix: PathBuf,
+    pub format: Format,
+}
+
+impl VizOptions {
+    /// Writes the recording of `part`, returning the path of the file or folder it was written to.
+    pub fn export(&self, recording: &Recording, part: u8) -> io::Result<PathBuf> {
+        let mut name = self.prefix.file_name().unwrap_or_default().to_os_string();
+        name.push(format!("-{part}"));
+        let base = self.prefix.with_file_name(name);
+        if let Some(folder) = base.parent() {
+            fs::create_dir_all(folder)?;
+        }
+
+        match self.format {
+            Format::Terminal => {
+                let path = base.with_extension("sh");
+                fs::write(&path, replay_script(&recording.frames))?;
+                Ok(path)
+            }
+            Format::Ppm | Format::Png => {
+                fs::create_dir_all(&base)?;
+                let canvas = Canvas::new(&recording.frames);
+                for (i, frame) in recording.frames.iter().enumerate() {
+                    let (extension, image) = if self.format == Format::Ppm {
+                        ("ppm", canvas.ppm(frame))
+                    } else {
+                        ("png", canvas.png(frame)?)
+                    };
+                    fs::write(base.join(format!("{i:04}.{extension}")), image)?;
+                }
+                Ok(base)
+            }
+            Format::Gif => {
+                let path = base.with_extension("gif");
+                fs::write(&path, gif(&recording.frames))?;
+                Ok(path)
+            }
+        }
+    }
+}
+
+/// A shell script that clears the terminal and prints each frame, run it with `sh`.
+pub fn replay_script(frames: &[String]) -> String {
+    let mut out = format!(
+        "#!/bin/sh\n# Replays {} frames recorded with `--viz`.\n",
+        frames.len()
+    );
+    for frame in frames {
+        let _ = write!(
+            out,
+            "printf '\\033[H\\033[2J'\ncat <<'FRAME'\n{}\nFRAME\nsleep {}\n",
+            frame.trim_end_matches('\n'),
+            f64::from(FRAME_MS) / 1000.0
+        );
+    }
+    out
+}
+
+/// Color of a cell: dark for empty cells, light for walls, yellow for robots and guards,
+/// brown for boxes. Digits are shades of green, other characters get a color of a small palette.
+pub fn color(ch: char) -> [u8; 3] {
+    const OTHERS: [[u8; 3]; 6] = [
+        [80, 160, 255],
+        [230, 90, 90],
+        [120, 220, 120],
+        [220, 120, 220],
+        [90, 220, 220],
+        [240, 160, 60],
+    ];
+
+    match ch {
+        ' ' | '.' => [16, 20, 40],
+        '#' => [190, 190, 200],
+        '@' | '^' | 'v' | '<' | '>' => [255, 200, 40],
+        'O' | '[' | ']' => [170, 110, 50],
+        '0'..='9' => [20, 60 + (ch as u8 - b'0') * 20, 40],
+        _ => OTHERS[ch as usize % OTHERS.len()],
+    }
+}
+
+/// Pixel dimensions shared by all frames of a recording.
+struct Canvas {
+    columns: usize,
+    rows: usize,
+    scale: usize,
+    /// Characters in the order they first appear, at most 256. Cells beyond a line are `' '`.
+    palette: Vec<char>,
+}
+
+impl Canvas {
+    fn new(frames: &[String]) -> Self {
+        let mut palette = vec![' '];
+        let (mut columns, mut rows) = (1, 1);
+        for frame in frames {
+            rows = rows.max(frame.lines().count());
+            for line in frame.lines() {
+                columns = columns.max(line.chars().count());
+                for ch in line.chars() {
+                    if palette.len() < 256 && !palette.contains(&ch) {
+                        palette.push(ch);
+                    }
+                }
+            }
+        }
+
+        Self {
+            columns,
+            rows,
+            scale: (MAX_SIDE / columns.max(rows)).clamp(1, MAX_SCALE),
+            palette,
+        }
+    }
+
+    fn width(&self) -> usize {
+        self.columns * self.scale
+    }
+
+    fn height(&self) -> usize {
+        self.rows * self.scale
+    }
+
+    /// The palette index of every pixel of `frame`, row by row.
+    fn indices(&self, frame: &str) -> Vec<u8> {
+        let mut cells = vec![0u8; self.columns * self.rows];
+        for (y, line) in frame.lines().take(self.rows).enumerate() {
+            for (x, ch) in line.chars().take(self.columns).enumerate() {
+                let index = self.palette.iter().position(|&p| p == ch).unwrap_or(0);
+                cells[y * self.columns + x] = index as u8;
+            }
+        }
+
+        let mut pixels = Vec::with_capacity(self.width() * self.height());
+        for row in cells.chunks(self.columns) {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|&cell| std::iter::repeat_n(cell, self.scale))
+                .collect();
+            for _ in 0..self.scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        pixels
+    }
+
+    fn rgb(&self, frame: &str) -> Vec<u8> {
+        self.indices(frame)
+            .into_iter()
+            .flat_map(|index| color(self.palette[index as usize]))
+            .collect()
+    }
+
+    fn ppm(&self, frame: &str) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width(), self.height()).into_bytes();
+        out.extend(self.rgb(frame));
+        out
+    }
+
+    fn png(&self, frame: &str) -> io::Result<Vec<u8>> {
+        let mut header = vec![];
+        header.extend(
+            u32::try_from(self.width())
+                .unwrap_or(u32::MAX)
+                .to_be_bytes(),
+        );
+        header.extend(
+            u32::try_from(self.height())
+                .unwrap_or(u32::MAX)
+                .to_be_bytes(),
+        );
+        // 8 bits per channel, RGB, default compression, filter and no interlacing.
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+        for row in self.rgb(frame).chunks(self.width() * 3) {
+            // every scanline starts with its filter type, none.
+            encoder.write_all(&[0])?;
+            encoder.write_all(row)?;
+        }
+
+        let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut out, b"IHDR", &header);
+        png_chunk(&mut out, b"IDAT", &encoder.finish()?);
+        png_chunk(&mut out, b"IEND", &[]);
+        Ok(out)
+    }
+}
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend(u32::try_from(data.len()).unwrap_or(u32::MAX).to_be_bytes());
+    out.extend(kind);
+    out.extend(data);
+    let mut crc = Crc::new();
+    crc.update(kind);
+    crc.update(data);
+    out.extend(crc.sum().to_be_bytes());
+}
+
+/// An animated GIF of all frames that loops forever.
+pub fn gif(frames: &[String]) -> Vec<u8> {
+    let canvas = Canvas::new(frames);
+    let size = |n: usize| u16::try_from(n).unwrap_or(u16::MAX).to_le_bytes();
+    // the color table has 2^bits entries.
+    let bits = (usize::BITS - (canvas.palette.len() - 1).leading_zeros()).max(1) as u8;
+
+    let mut out = b"GIF89a".to_vec();
+    out.extend(size(canvas.width()));
+    out.extend(size(canvas.height()));
+    out.extend([0x80 | ((bits - 1) << 4) | (bits - 1), 0, 0]);
+    for i in 0..1 << bits {
+        out.extend(canvas.palette.get(i).map_or([0; 3], |&ch| color(ch)));
+    }
+    out.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    for (i, frame) in frames.iter().enumerate() {
+        let delay = if i + 1 == frames.len() {
+            LAST_FRAME_MS
+        } else {
+            FRAME_MS
+        };
+        out.extend([0x21, 0xf9, 0x04, 0x00]);
+        out.extend((delay / 10).to_le_bytes());
+        out.extend([0x00, 0x00]);
+
+        out.extend([0x2c, 0, 0, 0, 0]);
+        out.extend(size(canvas.width()));
+        out.extend(size(canvas.height()));
+        out.push(0);
+
+        let min_code_size = bits.max(2);
+        out.push(min_code_size);
+        for block in lzw(min_code_size, &canvas.indices(frame)).chunks(255) {
+            out.push(block.len() as u8);
+            out.extend(block);
+        }
+        out.push(0);
+    }
+
+    out.push(0x3b);
+    out
+}
+
+/// Codes of variable width, packed starting at the least significant bit as GIF expects.
+#[derive(Default)]
+struct Bits {
+    out: Vec<u8>,
+    pending: u32,
+    len: u32,
+}
+
+impl Bits {
+    fn write(&mut self, code: u16, width: u32) {
+        self.pending |= u32::from(code) << self.len;
+        self.len += width;
+        while self.len >= 8 {
+            self.out.push(self.pending as u8);
+            self.pending >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.out.push(self.pending as u8);
+        }
+        self.out
+    }
+}
+
+/// The variable length LZW compression of GIF image data.
+fn lzw(min_code_size: u8, indices: &[u8]) -> Vec<u8> {
+    const MAX_CODE: u16 = 4095;
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+    let reset_width = u32::from(min_code_size) + 1;
+
+    let mut bits = Bits::default();
+    let mut width = reset_width;
+    // the latest code in the table, and the first one that needs another bit.
+    let mut latest = end;
+    let mut overflow = 1u16 << reset_width;
+    let mut table: HashMap<(u16, u8), u16> = HashMap::default();
+
+    // adds a code to the table, starting over when the table is full.
+    let mut next_code = |bits: &mut Bits, width: &mut u32, table: &mut HashMap<_, _>| {
+        latest += 1;
+        if latest == overflow {
+            *width += 1;
+            overflow <<= 1;
+        }
+        if latest == MAX_CODE {
+            bits.write(clear, *width);
+            *width = reset_width;
+            latest = end;
+            overflow = 1 << reset_width;
+            table.clear();
+            return None;
+        }
+        Some(latest)
+    };
+
+    bits.write(clear, width);
+    let Some((&first, rest)) = indices.split_first() else {
+        bits.write(end, width);
+        return bits.finish();
+    };
+
+    let mut code = u16::from(first);
+    for &index in rest {
+        if let Some(&known) = table.get(&(code, index)) {
+            code = known;
+            continue;
+        }
+        bits.write(code, width);
+        if let Some(new) = next_code(&mut bits, &mut width, &mut table) {
+            table.insert((code, index), new);
+        }
+        code = u16::from(index);
+    }
+    bits.write(code, width);
+    let _ = next_code(&mut bits, &mut width, &mut table);
+    bits.write(end, width);
+    bits.finish()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{gif, lzw, replay_script, Canvas, Format, Recording, VizOptions, MAX_FRAMES};
+
+    /// Decodes GIF image data like a GIF viewer would.
+    fn unlzw(min_code_size: u8, data: &[u8]) -> Vec<u8> {
+        let clear = 1usize << min_code_size;
+        let reset = || -> Vec<Vec<u8>> {
+            let mut table: Vec<Vec<u8>> = (0..clear).map(|i| vec![i as u8]).collect();
+            table.extend([vec![], vec![]]);
+            table
+        };
+        let mut table = reset();
+        let mut width = u32::from(min_code_size) + 1;
+        let (mut pending, mut len, mut bytes) = (0u32, 0u32, data.iter());
+        let mut previous: Option<Vec<u8>> = None;
+        let mut out = vec![];
+
+        loop {
+            while len < width {
+                pending |= u32::from(*bytes.next().expect("end code")) << len;
+                len += 8;
+            }
+            let code = (pending & ((1 << width) - 1)) as usize;
+            pending >>= width;
+            len -= width;
+
+            if code == clear {
+                table = reset();
+                width = u32::from(min_code_size) + 1;
+                previous = None;
+                continue;
+            }
+            if code == clear + 1 {
+                return out;
+            }
+
+            let entry = match (table.get(code), &previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(previous)) => [previous.clone(), vec![previous[0]]].concat(),
+                (None, None) => panic!("unknown code {code}"),
+            };
+            out.extend(&entry);
+            if let Some(previous) = previous {
+                table.push([previous, vec![entry[0]]].concat());
+                if table.len() == 1 << width && width < 12 {
+                    width += 1;
+                }
+            }
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn compresses_like_gif() {
+        let repetitive: Vec<u8> = (0..20_000).map(|i| ((i / 7) % 3) as u8).collect();
+        let noisy: Vec<u8> = (0..20_000u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 27) as u8)
+            .collect();
+
+        for (min_code_size, data) in [(2, &repetitive), (5, &noisy), (2, &vec![])] {
+            let compressed = lzw(min_code_size, data);
+            assert_eq!(&unlzw(min_code_size, &compressed), data);
+        }
+        assert!(lzw(2, &repetitive).len() < 1000);
+    }
+
+    #[test]
+    fn keeps_frames_evenly_spaced() {
+        let mut recording = Recording::new();
+        let mut rendered = 0;
+        for i in 0..MAX_FRAMES * 3 {
+            recording.push(|| {
+                rendered += 1;
+                i.to_string()
+            });
+        }
+
+        assert_eq!(recording.offered, MAX_FRAMES * 3);
+        assert_eq!(recording.frames.len(), MAX_FRAMES * 3 / 4);
+        assert_eq!(recording.frames[..3], ["0", "4", "8"]);
+        assert!(rendered < MAX_FRAMES * 2);
+    }
+
+    #[test]
+    fn scales_cells_to_pixels() {
+        let frames = ["#.\n.@".to_string(), "#.@\n".to_string()];
+        let canvas = Canvas::new(&frames);
+        assert_eq!((canvas.columns, canvas.rows, canvas.scale), (3, 2, 8));
+        assert_eq!(canvas.palette, [' ', '#', '.', '@']);
+
+        let pixels = canvas.indices(&frames[1]);
+        assert_eq!(pixels.len(), 24 * 16);
+        assert_eq!(
+            (pixels[0], pixels[8], pixels[16], pixels[24 * 8]),
+            (1, 2, 3, 0)
+        );
+        assert!(canvas.ppm(&frames[0]).starts_with(b"P6\n24 16\n255\n"));
+        assert_eq!(canvas.png(&frames[0]).unwrap()[12..16], *b"IHDR");
+    }
+
+    #[test]
+    fn writes_animations() {
+        let frames = vec!["#.\n.@\n".to_string(), "#@\n..\n".to_string()];
+        let animation = gif(&frames);
+        assert!(animation.starts_with(b"GIF89a\x10\x00\x10\x00"));
+        assert_eq!(animation.last(), Some(&0x3b));
+        assert_eq!(
+            animation
+                .windows(4)
+                .filter(|w| w == b"\x21\xf9\x04\x00")
+                .count(),
+            2
+        );
+
+        let script = replay_script(&frames);
+        assert!(script.contains("cat <<'FRAME'\n#@\n..\nFRAME\nsleep 0.05\n"));
+
+        let folder = std::env::temp_dir().join(format!("aoc-viz-{}", std::process::id()));
+        let mut recording = Recording::new();
+        recording.frames = frames;
+        let options = VizOptions {
+            prefix: folder.join("14"),
+            format: Format::Png,
+        };
+        let path = options.export(&recording, 2).unwrap();
+        assert_eq!(path, folder.join("14-2"));
+        assert!(path.join("0001.png").exists());
+        std::fs::remove_dir_all(folder).unwrap();
+    }
+}